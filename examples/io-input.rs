@@ -1,20 +1,32 @@
 use prettui::color::Color;
+use prettui::io::completion::WordCompleter;
+use prettui::io::history::History;
 use prettui::io::input::{InputConfig, read_input, read_multiline_input, read_secret_input};
+use prettui::style::Style;
+use std::sync::Arc;
 
 fn main() -> std::io::Result<()> {
     let cfg = InputConfig {
         prefix: String::from("[TEST] "),
         prompt: String::from(">> "),
-        prefix_color: Color::Magenta,
-        prompt_color: Color::Cyan,
-        input_text_color: Color::White,
+        prefix_color: Style::new().fg(Color::Magenta).bold(),
+        prompt_color: Color::Cyan.into(),
+        input_text_color: Color::White.into(),
         max_chars_per_line: 80,
         indent_level: 2,
+        history: Some(History::new()),
+        completer: Some(Arc::new(WordCompleter::new([
+            "build", "bench", "check", "clean", "test",
+        ]))),
+        placeholder: Some(String::from("command, e.g. build")),
+        ..Default::default()
     };
 
-    // Single-line input
+    // Single-line input with editing; Tab completes a command, Up recalls the previous line
     let subject = read_input(&cfg)?;
     println!("Subject: {}", subject);
+    let again = read_input(&cfg)?;
+    println!("Again: {}", again);
 
     // Multiline body: prompt shown once, end with '.' line
     let body = read_multiline_input(&cfg, ".")?;
@@ -1,4 +1,5 @@
 use prettui::color::Color;
+use prettui::style::Style;
 use prettui::io::output::{OutputConfig, write_output};
 
 fn main() -> std::io::Result<()> {
@@ -10,18 +11,22 @@ fn main() -> std::io::Result<()> {
     )?;
 
     // Example 2: with prefix and indent
-    let mut cfg2 = OutputConfig::default();
-    cfg2.prefix = String::from("[App] ");
-    cfg2.prefix_color = Color::Blue;
-    cfg2.indent_level = 4;
+    let cfg2 = OutputConfig {
+        prefix: String::from("[App] "),
+        prefix_color: Color::Blue.into(),
+        indent_level: 4,
+        ..Default::default()
+    };
     write_output(&cfg2, "Indented message with a prefix.")?;
 
-    // Example 3: with log level tag and custom text color
-    let mut cfg3 = OutputConfig::default();
-    cfg3.log_level = Some(String::from("INFO"));
-    cfg3.text_color = Color::Green;
-    cfg3.prefix = String::from("[Server] ");
-    cfg3.prefix_color = Color::Magenta;
+    // Example 3: with log level tag and custom text style
+    let cfg3 = OutputConfig {
+        log_level: Some(String::from("INFO")),
+        text_color: Color::Green.into(),
+        prefix: String::from("[Server] "),
+        prefix_color: Style::new().fg(Color::Magenta).bold(),
+        ..Default::default()
+    };
     write_output(&cfg3, "Server started on port 8080.")?;
 
     Ok(())
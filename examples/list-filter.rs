@@ -0,0 +1,22 @@
+use prettui::prelude::*;
+
+fn main() -> anyhow::Result<()> {
+    let commands = [
+        "build", "check", "clean", "clippy", "doc", "fmt", "install", "new", "publish", "run",
+        "search", "test", "tree", "update",
+    ];
+    let config = ListConfig::default()
+        .items_per_row(2)
+        .rows_per_page(5)
+        .cell_width(24)
+        .filterable(true)
+        .match_fg(Color::Cyan);
+
+    println!("Type to filter, arrows to navigate, Enter to confirm, Esc to cancel.");
+    match choose_from_list(&commands, &config)? {
+        Some(idx) => println!("You chose: cargo {}", commands[idx]),
+        None => println!("Selection cancelled."),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use prettui::prelude::*;
+
+fn main() -> anyhow::Result<()> {
+    let groups = vec![
+        ListGroup::new("Local", vec!["main", "feature/login", "fix/typo"]),
+        ListGroup::new("Remote", vec!["origin/main", "origin/develop"]),
+        ListGroup::new(
+            "Archived",
+            vec!["old/prototype", "old/spike", "old/demo", "old/v1"],
+        ),
+    ];
+    let config = ListConfig::default()
+        .items_per_row(2)
+        .rows_per_page(6)
+        .cell_width(24)
+        .filterable(true);
+
+    println!("Pick a branch; PageUp/PageDown jump between groups.");
+    match choose_from_groups(&groups, &config)? {
+        Some((group, idx)) => println!(
+            "You chose {} from {}",
+            groups[group].items[idx], groups[group].title
+        ),
+        None => println!("Selection cancelled."),
+    }
+
+    Ok(())
+}
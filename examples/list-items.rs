@@ -0,0 +1,36 @@
+use prettui::prelude::*;
+
+fn main() -> anyhow::Result<()> {
+    let files = vec![
+        ListEntry::separator("Project"),
+        ListEntry::new("Cargo.toml")
+            .with_icon(Span::new("# ").fg(Color::DarkYellow))
+            .with_description("Package manifest")
+            .with_metadata("1 KB"),
+        ListEntry::new("src/lib.rs")
+            .with_icon(Span::new("* ").fg(Color::Green))
+            .with_description("Library entry point")
+            .with_metadata("3 KB"),
+        ListEntry::new("target/")
+            .with_icon(Span::new("/ ").fg(Color::Blue))
+            .with_description("Build output, cannot be opened")
+            .with_disabled(true),
+        ListEntry::separator("Docs"),
+        ListEntry::new("README.md")
+            .with_icon(Span::new("~ ").fg(Color::Cyan))
+            .with_description("Project overview")
+            .with_metadata("4 KB"),
+    ];
+    let config = ListConfig::default()
+        .items_per_row(1)
+        .rows_per_page(6)
+        .cell_width(40);
+
+    println!("Pick a file to open");
+    match choose_from_list(&files, &config)? {
+        Some(idx) => println!("Opening {}", files[idx].filter_text()),
+        None => println!("Selection cancelled."),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use prettui::prelude::*;
+
+fn main() -> anyhow::Result<()> {
+    let packages = [
+        "curl", "git", "htop", "jq", "neovim", "ripgrep", "tmux", "wget", "zsh",
+    ];
+    let config = ListConfig::default()
+        .items_per_row(3)
+        .rows_per_page(3)
+        .cell_width(22)
+        .preselected([1, 5])
+        .min_selected(1)
+        .max_selected(5);
+
+    println!("Select packages to install");
+    println!("Space toggles, Ctrl+A selects all, Ctrl+R inverts, Enter confirms, Esc cancels.");
+    match choose_many_from_list(&packages, &config)? {
+        Some(chosen) => {
+            let names: Vec<&str> = chosen.iter().map(|&idx| packages[idx]).collect();
+            println!("Installing: {}", names.join(", "));
+        }
+        None => println!("Selection cancelled."),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use prettui::prelude::*;
+
+fn main() -> anyhow::Result<()> {
+    let commits = [
+        ("a1b2c3d", "Fix panic on empty input", "Guard against an empty buffer before parsing.\n\nReported by several users on Windows."),
+        ("d4e5f6a", "Add multi-select lists", "Space toggles items, Ctrl+A selects all and Ctrl+R inverts the selection."),
+        ("b7c8d9e", "Update crossterm", "Bump crossterm to 0.29 and adapt to the new event API."),
+        ("f0a1b2c", "Document prompts", "Add examples for confirm, read_matching and read_number."),
+    ];
+    let labels: Vec<String> = commits
+        .iter()
+        .map(|(hash, subject, _)| format!("{} {}", hash, subject))
+        .collect();
+    let bodies: Vec<String> = commits
+        .iter()
+        .map(|(_, _, body)| body.to_string())
+        .collect();
+
+    let config = ListConfig::default()
+        .items_per_row(1)
+        .rows_per_page(6)
+        .cell_width(36)
+        .preview(move |idx| bodies[idx].clone())
+        .preview_width(36);
+
+    println!("Pick a commit to inspect");
+    match choose_from_list(&labels, &config)? {
+        Some(idx) => println!("You chose: {}", labels[idx]),
+        None => println!("Selection cancelled."),
+    }
+
+    let config = config
+        .preview_position(PreviewPosition::Bottom)
+        .preview_height(3);
+    println!("Same list with the preview below");
+    if let Some(idx) = choose_from_list(&labels, &config)? {
+        println!("You chose: {}", labels[idx]);
+    }
+
+    Ok(())
+}
@@ -7,11 +7,13 @@ fn main() -> anyhow::Result<()> {
         .rows_per_page(10)
         .cell_width(30)
         .normal_fg(Color::DarkGrey)
-        .highlight_fg(Color::Green);
+        .highlight_fg(Color::Green)
+        .mouse(true)
+        .keymap(KeyMap::vim());
 
     println!("Example of using");
     println!(
-        "Use arrows/PageUp/PageDown to navigate, type digits, Backspace to delete, Enter to confirm, Esc to cancel."
+        "Use arrows/PageUp/PageDown, hjkl/g/G/Ctrl-d/Ctrl-u or the mouse to navigate, type digits, Backspace to delete, Enter or double-click to confirm, Esc to cancel."
     );
     if let Some(idx) = choose_from_list(&items, &config)? {
         println!("You chose: {}", items[idx]);
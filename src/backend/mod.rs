@@ -0,0 +1,441 @@
+//! Terminal backends used by the `io` and `list` modules.
+//!
+//! Every prompt and list reads events, lines and terminal state, and writes
+//! its output, through the backend installed for the current thread. By
+//! default that is [`CrosstermBackend`], which talks to the real terminal.
+//! [`with_backend`] swaps in another backend for the duration of a closure,
+//! typically a [`VirtualTerminal`] fed with scripted keystrokes, so code using
+//! prettui can be tested without a terminal.
+//!
+//! # Example
+//!
+//! ```rust
+//! use prettui::backend::{with_backend, VirtualTerminal};
+//! use prettui::io::input::{read_input, InputConfig};
+//! use prettui::list::{choose_from_list, KeyCode, ListConfig};
+//!
+//! let vt = VirtualTerminal::new(80, 24);
+//! vt.push_keys([KeyCode::Right, KeyCode::Right, KeyCode::Enter]);
+//! vt.type_line("Ferris");
+//!
+//! let (choice, name) = with_backend(vt.clone(), || {
+//!     let choice = choose_from_list(&["red", "green", "blue"], &ListConfig::default())?;
+//!     let name = read_input(&InputConfig::default())?;
+//!     anyhow::Ok((choice, name))
+//! })?;
+//!
+//! assert_eq!(choice, Some(2));
+//! assert_eq!(name, "Ferris");
+//! assert!(vt.screen_text().contains(">> Ferris"));
+//! # anyhow::Ok(())
+//! ```
+
+use crate::color::{self, Color, ColorDepth};
+use crossterm::event::Event;
+use std::cell::RefCell;
+use std::io::{self, IsTerminal, Write};
+use std::thread::LocalKey;
+
+pub mod virtual_terminal;
+
+pub use virtual_terminal::VirtualTerminal;
+
+/// A source of terminal input and a sink for terminal output.
+pub trait Backend {
+    /// Write bytes to standard output.
+    fn write(&mut self, buf: &[u8]) -> io::Result<()>;
+
+    /// Write bytes to standard error.
+    fn write_err(&mut self, buf: &[u8]) -> io::Result<()>;
+
+    /// Flush buffered output.
+    fn flush(&mut self) -> io::Result<()>;
+
+    /// Block until the next terminal event (key, mouse, resize, ...).
+    fn read_event(&mut self) -> io::Result<Event>;
+
+    /// Read a line of cooked input into `buf`, including the newline.
+    /// Returns the number of bytes read; `0` means end of input.
+    fn read_line(&mut self, buf: &mut String) -> io::Result<usize>;
+
+    /// Terminal size as `(columns, rows)`.
+    fn size(&self) -> io::Result<(u16, u16)>;
+
+    /// Cursor position as `(column, row)`, both zero-based.
+    fn cursor_position(&mut self) -> io::Result<(u16, u16)>;
+
+    /// Switch the terminal to raw mode (no echo, no line buffering).
+    fn enable_raw_mode(&mut self) -> io::Result<()>;
+
+    /// Restore cooked mode.
+    fn disable_raw_mode(&mut self) -> io::Result<()>;
+
+    /// Whether input comes from a terminal, so raw-mode editing is possible.
+    fn is_interactive(&self) -> bool;
+
+    /// Whether standard output goes to a terminal. When it does not, escape
+    /// codes other than colors are removed from everything written to it, and
+    /// colors unless forced (see [`ColorChoice`](crate::color::ColorChoice)). Defaults to
+    /// [`is_interactive`](Backend::is_interactive).
+    fn stdout_is_terminal(&self) -> bool {
+        self.is_interactive()
+    }
+
+    /// Whether standard error goes to a terminal, like
+    /// [`stdout_is_terminal`](Backend::stdout_is_terminal). Defaults to
+    /// [`is_interactive`](Backend::is_interactive).
+    fn stderr_is_terminal(&self) -> bool {
+        self.is_interactive()
+    }
+
+    /// How many colors the terminal can show; colors are downgraded to fit.
+    /// Defaults to [`ColorDepth::detect`].
+    fn color_depth(&self) -> ColorDepth {
+        ColorDepth::detect()
+    }
+}
+
+/// Backend for the real terminal, using crossterm and the process's
+/// standard streams.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CrosstermBackend;
+
+impl Backend for CrosstermBackend {
+    fn write(&mut self, buf: &[u8]) -> io::Result<()> {
+        io::stdout().write_all(buf)
+    }
+
+    fn write_err(&mut self, buf: &[u8]) -> io::Result<()> {
+        io::stderr().write_all(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        io::stdout().flush()?;
+        io::stderr().flush()
+    }
+
+    fn read_event(&mut self) -> io::Result<Event> {
+        crossterm::event::read()
+    }
+
+    fn read_line(&mut self, buf: &mut String) -> io::Result<usize> {
+        io::stdin().read_line(buf)
+    }
+
+    fn size(&self) -> io::Result<(u16, u16)> {
+        crossterm::terminal::size()
+    }
+
+    fn cursor_position(&mut self) -> io::Result<(u16, u16)> {
+        crossterm::cursor::position()
+    }
+
+    fn enable_raw_mode(&mut self) -> io::Result<()> {
+        crossterm::terminal::enable_raw_mode()
+    }
+
+    fn disable_raw_mode(&mut self) -> io::Result<()> {
+        crossterm::terminal::disable_raw_mode()
+    }
+
+    fn is_interactive(&self) -> bool {
+        io::stdin().is_terminal()
+    }
+
+    fn stdout_is_terminal(&self) -> bool {
+        io::stdout().is_terminal()
+    }
+
+    fn stderr_is_terminal(&self) -> bool {
+        io::stderr().is_terminal()
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Box<dyn Backend>>> = RefCell::new(None);
+    static STDOUT_FILTER: RefCell<EscapeFilter> = const { RefCell::new(EscapeFilter::new()) };
+    static STDERR_FILTER: RefCell<EscapeFilter> = const { RefCell::new(EscapeFilter::new()) };
+}
+
+/// Run `f` with `backend` installed as the current thread's backend.
+///
+/// The previous backend is restored when `f` returns or panics. To inspect a
+/// [`VirtualTerminal`] afterwards, pass a clone; clones share the same screen.
+pub fn with_backend<B: Backend + 'static, R>(backend: B, f: impl FnOnce() -> R) -> R {
+    struct Restore(Option<Box<dyn Backend>>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            CURRENT.with(|current| *current.borrow_mut() = previous);
+        }
+    }
+
+    let previous = CURRENT.with(|current| current.borrow_mut().replace(Box::new(backend)));
+    let _restore = Restore(previous);
+    f()
+}
+
+/// Call `f` with the current thread's backend.
+fn current<R>(f: impl FnOnce(&mut dyn Backend) -> R) -> R {
+    CURRENT.with(|current| match current.borrow_mut().as_deref_mut() {
+        Some(backend) => f(backend),
+        None => f(&mut CrosstermBackend),
+    })
+}
+
+/// Writer for the current backend's standard output.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Stdout;
+
+impl Write for Stdout {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        current(|backend| {
+            let (terminal, depth) = (backend.stdout_is_terminal(), backend.color_depth());
+            write_filtered(&STDOUT_FILTER, terminal, depth, buf, |buf| {
+                backend.write(buf)
+            })
+        })?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        current(|backend| backend.flush())
+    }
+}
+
+/// Writer for the current backend's standard error.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Stderr;
+
+impl Write for Stderr {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        current(|backend| {
+            let (terminal, depth) = (backend.stderr_is_terminal(), backend.color_depth());
+            write_filtered(&STDERR_FILTER, terminal, depth, buf, |buf| {
+                backend.write_err(buf)
+            })
+        })?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        current(|backend| backend.flush())
+    }
+}
+
+/// Write `buf` to a stream with `write`, leaving out the escape codes it
+/// should not get: everything but colors when it is not a `terminal`, and
+/// colors when they are disabled for it (see [`ColorChoice`](crate::color::ColorChoice)).
+/// Colors are downgraded to `depth`.
+fn write_filtered(
+    filter: &'static LocalKey<RefCell<EscapeFilter>>,
+    terminal: bool,
+    depth: ColorDepth,
+    buf: &[u8],
+    write: impl FnOnce(&[u8]) -> io::Result<()>,
+) -> io::Result<()> {
+    let colors = color::colors_enabled(terminal).then_some(depth);
+    if terminal && colors == Some(ColorDepth::TrueColor) {
+        return write(buf);
+    }
+    let text = filter.with(|filter| filter.borrow_mut().filter(buf, terminal, colors));
+    write(&text)
+}
+
+/// Position within an ANSI escape sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Escape {
+    /// Plain text.
+    None,
+    /// After `ESC`.
+    Start,
+    /// In a control sequence (`ESC [`), up to its final byte.
+    Csi,
+    /// In an operating system command (`ESC ]`), up to `BEL` or `ESC \`.
+    Osc,
+    /// After `ESC` within an operating system command.
+    OscEnd,
+}
+
+/// Removes escape sequences from a stream. A sequence split across writes
+/// is held back until it is complete.
+#[derive(Debug)]
+struct EscapeFilter {
+    escape: Escape,
+    /// Bytes of the unfinished escape sequence.
+    sequence: Vec<u8>,
+}
+
+impl EscapeFilter {
+    const fn new() -> Self {
+        Self {
+            escape: Escape::None,
+            sequence: Vec::new(),
+        }
+    }
+
+    /// `buf` without escape sequences other than graphic renditions (SGR)
+    /// unless `controls`. The colors of graphic renditions are downgraded to
+    /// the `colors` depth, or left out if `None`; graphic renditions are
+    /// dropped entirely if neither is set.
+    fn filter(&mut self, buf: &[u8], controls: bool, colors: Option<ColorDepth>) -> Vec<u8> {
+        let mut text = Vec::with_capacity(buf.len());
+        for &byte in buf {
+            if self.escape == Escape::None && byte != 0x1b {
+                text.push(byte);
+                continue;
+            }
+            self.sequence.push(byte);
+            self.escape = match (self.escape, byte) {
+                (Escape::None, _) => Escape::Start,
+                (Escape::Start, b'[') => Escape::Csi,
+                (Escape::Start, b']') => Escape::Osc,
+                (Escape::Csi, 0x40..=0x7e) => Escape::None,
+                (Escape::Csi, _) => Escape::Csi,
+                (Escape::Osc, 0x07) => Escape::None,
+                (Escape::Osc, 0x1b) => Escape::OscEnd,
+                (Escape::Osc, _) => Escape::Osc,
+                // Two-byte sequences such as `ESC 7`, and the end of `ESC \`
+                (Escape::Start | Escape::OscEnd, _) => Escape::None,
+            };
+            if self.escape != Escape::None {
+                continue;
+            }
+            let sequence = std::mem::take(&mut self.sequence);
+            let graphics = sequence
+                .strip_prefix(b"\x1b[")
+                .and_then(|rest| rest.strip_suffix(b"m"));
+            match graphics {
+                Some(_) if colors == Some(ColorDepth::TrueColor) => text.extend(sequence),
+                Some(params) if controls || colors.is_some() => {
+                    text.extend(graphics_with(params, colors))
+                }
+                Some(_) => {}
+                None if controls => text.extend(sequence),
+                None => {}
+            }
+        }
+        text
+    }
+}
+
+/// The graphic rendition sequence with `params`, with its colors downgraded
+/// to `colors` or left out if `None`. Empty if nothing is left.
+fn graphics_with(params: &[u8], colors: Option<ColorDepth>) -> Vec<u8> {
+    let params = String::from_utf8_lossy(params);
+    if params.is_empty() {
+        return b"\x1b[m".to_vec();
+    }
+    let mut kept = Vec::new();
+    let mut iter = params.split(';');
+    while let Some(param) = iter.next() {
+        match param.parse::<u8>() {
+            // Extended colors: `38;5;n` or `38;2;r;g;b`
+            Ok(base @ (38 | 48 | 58)) => {
+                let mut number = || iter.next().and_then(|n| n.parse().ok());
+                let color = match number() {
+                    Some(5) => number().map(Color::Ansi256),
+                    Some(2) => match (number(), number(), number()) {
+                        (Some(r), Some(g), Some(b)) => Some(Color::Rgb { r, g, b }),
+                        _ => None,
+                    },
+                    _ => None,
+                };
+                if let (Some(color), Some(depth)) = (color, colors) {
+                    kept.push(extended_color(base, color.downgrade(depth)));
+                }
+            }
+            Ok(30..=37 | 39..=47 | 49 | 59 | 90..=97 | 100..=107) if colors.is_none() => {}
+            // Extended colors with `:` separators
+            _ if colors.is_none() && ["38:", "48:", "58:"].iter().any(|p| param.starts_with(p)) => {
+            }
+            _ => kept.push(param.to_string()),
+        }
+    }
+    if kept.is_empty() {
+        Vec::new()
+    } else {
+        format!("\x1b[{}m", kept.join(";")).into_bytes()
+    }
+}
+
+/// Parameters of an extended color: `base` (38 for foreground, 48 for
+/// background, 58 for underline) followed by the palette index or RGB value.
+/// Named colors use the standard codes (`31`, `91`, ...), which every
+/// terminal understands.
+fn extended_color(base: u8, color: Color) -> String {
+    match (color, color.ansi_index(), base) {
+        (Color::Rgb { r, g, b }, _, _) => format!("{};2;{};{};{}", base, r, g, b),
+        (_, Some(index @ 0..=7), 38 | 48) => (base - 8 + index).to_string(),
+        (_, Some(index @ 8..=15), 38 | 48) => (base + 52 + index - 8).to_string(),
+        (_, Some(index), _) => format!("{};5;{}", base, index),
+        (_, None, _) => (base + 1).to_string(),
+    }
+}
+
+/// Standard output of the current backend.
+pub fn stdout() -> Stdout {
+    Stdout
+}
+
+/// Standard error of the current backend.
+pub fn stderr() -> Stderr {
+    Stderr
+}
+
+/// Read the next event from the current backend.
+pub fn read_event() -> io::Result<Event> {
+    current(|backend| backend.read_event())
+}
+
+/// Read a line of cooked input from the current backend.
+pub fn read_line(buf: &mut String) -> io::Result<usize> {
+    current(|backend| backend.read_line(buf))
+}
+
+/// Size of the current backend's terminal as `(columns, rows)`.
+pub fn size() -> io::Result<(u16, u16)> {
+    current(|backend| backend.size())
+}
+
+/// Cursor position of the current backend as `(column, row)`.
+pub fn cursor_position() -> io::Result<(u16, u16)> {
+    current(|backend| backend.cursor_position())
+}
+
+/// Enable raw mode on the current backend.
+pub fn enable_raw_mode() -> io::Result<()> {
+    current(|backend| backend.enable_raw_mode())
+}
+
+/// Disable raw mode on the current backend.
+pub fn disable_raw_mode() -> io::Result<()> {
+    current(|backend| backend.disable_raw_mode())
+}
+
+/// Whether the current backend's input comes from a terminal.
+pub fn is_interactive() -> bool {
+    current(|backend| backend.is_interactive())
+}
+
+/// Whether the current backend's standard output goes to a terminal.
+pub fn stdout_is_terminal() -> bool {
+    current(|backend| backend.stdout_is_terminal())
+}
+
+/// Whether the current backend's standard error goes to a terminal.
+pub fn stderr_is_terminal() -> bool {
+    current(|backend| backend.stderr_is_terminal())
+}
+
+/// Whether both input and standard output of the current backend are
+/// terminals, so prompts and lists can be drawn interactively.
+pub fn is_terminal() -> bool {
+    current(|backend| backend.is_interactive() && backend.stdout_is_terminal())
+}
+
+/// How many colors the current backend's terminal can show.
+pub fn color_depth() -> ColorDepth {
+    current(|backend| backend.color_depth())
+}
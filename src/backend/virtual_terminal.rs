@@ -0,0 +1,479 @@
+//! An in-memory terminal for testing interactive code.
+//!
+//! [`VirtualTerminal`] implements [`Backend`]: input comes from a queue of
+//! scripted events, and output is interpreted (text, cursor movement, clearing
+//! and foreground colors) into a screen buffer that can be inspected.
+//! Reading past the end of the script returns an error instead of blocking.
+
+use super::Backend;
+use crate::color::{Color, ColorDepth};
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+use std::collections::VecDeque;
+use std::io;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Tab stops are every this many columns.
+const TAB_WIDTH: usize = 8;
+
+/// An in-memory terminal with a scripted input queue and a screen buffer.
+///
+/// Clones share the same state, so one clone can be installed with
+/// [`with_backend`](super::with_backend) while another is used to push input
+/// and inspect the screen.
+#[derive(Debug, Clone)]
+pub struct VirtualTerminal {
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Cell {
+    ch: char,
+    fg: Option<Color>,
+}
+
+impl Cell {
+    const BLANK: Cell = Cell { ch: ' ', fg: None };
+}
+
+#[derive(Debug)]
+enum Parser {
+    Ground,
+    Escape,
+    Csi(String),
+    Osc,
+}
+
+#[derive(Debug)]
+struct State {
+    width: usize,
+    height: usize,
+    cells: Vec<Vec<Cell>>,
+    col: usize,
+    row: usize,
+    saved: (usize, usize),
+    fg: Option<Color>,
+    color_depth: ColorDepth,
+    terminal: bool,
+    raw: bool,
+    parser: Parser,
+    partial: Vec<u8>,
+    output: Vec<u8>,
+    events: VecDeque<Event>,
+}
+
+impl VirtualTerminal {
+    /// Create a blank terminal of `width` columns and `height` rows with the
+    /// cursor in the top-left corner.
+    pub fn new(width: u16, height: u16) -> Self {
+        let (width, height) = (width.max(1) as usize, height.max(1) as usize);
+        Self {
+            state: Arc::new(Mutex::new(State {
+                width,
+                height,
+                cells: vec![vec![Cell::BLANK; width]; height],
+                col: 0,
+                row: 0,
+                saved: (0, 0),
+                fg: None,
+                color_depth: ColorDepth::TrueColor,
+                terminal: true,
+                raw: false,
+                parser: Parser::Ground,
+                partial: Vec::new(),
+                output: Vec::new(),
+                events: VecDeque::new(),
+            })),
+        }
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Report whether input and output are a terminal (the default). With
+    /// `false`, code under test behaves as if piped: lists and input use
+    /// their plain fallbacks, reading lines from the scripted keys.
+    pub fn set_terminal(&self, terminal: bool) {
+        self.state().terminal = terminal;
+    }
+
+    /// Queue an event to be read by the code under test.
+    pub fn push_event(&self, event: Event) {
+        self.state().events.push_back(event);
+    }
+
+    /// Queue a key press.
+    pub fn push_key(&self, key: impl Into<KeyEvent>) {
+        self.push_event(Event::Key(key.into()));
+    }
+
+    /// Queue several key presses.
+    pub fn push_keys<K: Into<KeyEvent>>(&self, keys: impl IntoIterator<Item = K>) {
+        for key in keys {
+            self.push_key(key);
+        }
+    }
+
+    /// Queue Ctrl+`c`.
+    pub fn push_ctrl(&self, c: char) {
+        self.push_key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::CONTROL));
+    }
+
+    /// Queue a key press for every character of `text`.
+    pub fn type_text(&self, text: &str) {
+        self.push_keys(text.chars().map(KeyCode::Char));
+    }
+
+    /// Queue `text` followed by Enter.
+    pub fn type_line(&self, text: &str) {
+        self.type_text(text);
+        self.push_key(KeyCode::Enter);
+    }
+
+    /// Number of queued events not yet read.
+    pub fn pending_events(&self) -> usize {
+        self.state().events.len()
+    }
+
+    /// Set how many colors the terminal reports it can show; colors written
+    /// to it are downgraded to fit. Truecolor by default.
+    pub fn set_color_depth(&self, depth: ColorDepth) {
+        self.state().color_depth = depth;
+    }
+
+    /// Resize the screen and queue the matching resize event.
+    pub fn resize(&self, width: u16, height: u16) {
+        let mut state = self.state();
+        let (width, height) = (width.max(1) as usize, height.max(1) as usize);
+        state.cells.resize(height, vec![Cell::BLANK; width]);
+        for line in &mut state.cells {
+            line.resize(width, Cell::BLANK);
+        }
+        state.width = width;
+        state.height = height;
+        state.col = state.col.min(width - 1);
+        state.row = state.row.min(height - 1);
+        state
+            .events
+            .push_back(Event::Resize(width as u16, height as u16));
+    }
+
+    /// Text of every screen row, with trailing spaces removed.
+    pub fn screen(&self) -> Vec<String> {
+        let state = self.state();
+        state
+            .cells
+            .iter()
+            .map(|line| {
+                let text: String = line.iter().map(|cell| cell.ch).collect();
+                text.trim_end().to_string()
+            })
+            .collect()
+    }
+
+    /// The screen as one string, without trailing blank rows.
+    pub fn screen_text(&self) -> String {
+        let mut rows = self.screen();
+        while rows.last().is_some_and(|row| row.is_empty()) {
+            rows.pop();
+        }
+        rows.join("\n")
+    }
+
+    /// Text of screen row `row`, with trailing spaces removed; empty if the
+    /// row is off screen.
+    pub fn line(&self, row: u16) -> String {
+        self.screen().get(row as usize).cloned().unwrap_or_default()
+    }
+
+    /// Foreground color of the character at `(col, row)`, if it was drawn
+    /// with one: a named color for the 16 standard colors, otherwise an
+    /// `Ansi256` or `Rgb` color.
+    pub fn fg_at(&self, col: u16, row: u16) -> Option<Color> {
+        let state = self.state();
+        state
+            .cells
+            .get(row as usize)
+            .and_then(|line| line.get(col as usize))
+            .and_then(|cell| cell.fg)
+    }
+
+    /// Cursor position as `(column, row)`.
+    pub fn cursor(&self) -> (u16, u16) {
+        let state = self.state();
+        (state.col.min(state.width - 1) as u16, state.row as u16)
+    }
+
+    /// Whether raw mode is currently enabled.
+    pub fn is_raw_mode(&self) -> bool {
+        self.state().raw
+    }
+
+    /// Everything written so far, escape sequences included.
+    pub fn output(&self) -> String {
+        String::from_utf8_lossy(&self.state().output).into_owned()
+    }
+}
+
+impl Backend for VirtualTerminal {
+    fn write(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.state().feed(buf);
+        Ok(())
+    }
+
+    fn write_err(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.state().feed(buf);
+        Ok(())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn read_event(&mut self) -> io::Result<Event> {
+        self.state()
+            .events
+            .pop_front()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::UnexpectedEof, "No scripted input left"))
+    }
+
+    /// Consume key events up to Enter, echoing them like a cooked terminal.
+    fn read_line(&mut self, buf: &mut String) -> io::Result<usize> {
+        let mut state = self.state();
+        let mut line = String::new();
+        while let Some(event) = state.events.pop_front() {
+            let Event::Key(key) = event else {
+                continue;
+            };
+            match key.code {
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    return Err(io::Error::new(io::ErrorKind::Interrupted, "Input canceled"));
+                }
+                KeyCode::Char(c) => {
+                    line.push(c);
+                    state.feed(c.encode_utf8(&mut [0; 4]).as_bytes());
+                }
+                KeyCode::Backspace if line.pop().is_some() => state.feed(b"\x08 \x08"),
+                KeyCode::Enter => {
+                    line.push('\n');
+                    state.feed(b"\r\n");
+                    break;
+                }
+                _ => {}
+            }
+        }
+        buf.push_str(&line);
+        Ok(line.len())
+    }
+
+    fn size(&self) -> io::Result<(u16, u16)> {
+        let state = self.state();
+        Ok((state.width as u16, state.height as u16))
+    }
+
+    fn cursor_position(&mut self) -> io::Result<(u16, u16)> {
+        Ok(self.cursor())
+    }
+
+    fn enable_raw_mode(&mut self) -> io::Result<()> {
+        self.state().raw = true;
+        Ok(())
+    }
+
+    fn disable_raw_mode(&mut self) -> io::Result<()> {
+        self.state().raw = false;
+        Ok(())
+    }
+
+    fn is_interactive(&self) -> bool {
+        self.state().terminal
+    }
+
+    fn color_depth(&self) -> ColorDepth {
+        self.state().color_depth
+    }
+}
+
+impl State {
+    /// Interpret output bytes, keeping incomplete UTF-8 sequences for later.
+    fn feed(&mut self, bytes: &[u8]) {
+        self.output.extend_from_slice(bytes);
+        self.partial.extend_from_slice(bytes);
+        let partial = std::mem::take(&mut self.partial);
+        let valid = match std::str::from_utf8(&partial) {
+            Ok(text) => text.len(),
+            Err(err) if err.error_len().is_none() => err.valid_up_to(),
+            Err(_) => {
+                for ch in String::from_utf8_lossy(&partial).chars() {
+                    self.put(ch);
+                }
+                return;
+            }
+        };
+        let text = std::str::from_utf8(&partial[..valid]).unwrap_or_default();
+        for ch in text.chars() {
+            self.put(ch);
+        }
+        self.partial = partial[valid..].to_vec();
+    }
+
+    fn put(&mut self, ch: char) {
+        match std::mem::replace(&mut self.parser, Parser::Ground) {
+            Parser::Ground => match ch {
+                '\x1b' => self.parser = Parser::Escape,
+                '\r' => self.col = 0,
+                '\n' => {
+                    // Raw mode disables output processing, so LF does not return
+                    if !self.raw {
+                        self.col = 0;
+                    }
+                    self.col = self.col.min(self.width - 1);
+                    self.line_feed();
+                }
+                '\x08' => self.col = self.col.min(self.width - 1).saturating_sub(1),
+                '\t' => self.col = ((self.col / TAB_WIDTH + 1) * TAB_WIDTH).min(self.width - 1),
+                c if c.is_control() => {}
+                c => self.print(c),
+            },
+            Parser::Escape => match ch {
+                '[' => self.parser = Parser::Csi(String::new()),
+                ']' => self.parser = Parser::Osc,
+                '7' => self.saved = (self.col, self.row),
+                '8' => (self.col, self.row) = self.saved,
+                _ => {}
+            },
+            Parser::Csi(mut params) => {
+                if ('\x40'..='\x7e').contains(&ch) {
+                    self.csi(&params, ch);
+                } else {
+                    params.push(ch);
+                    self.parser = Parser::Csi(params);
+                }
+            }
+            Parser::Osc => {
+                if ch != '\x07' && ch != '\x1b' {
+                    self.parser = Parser::Osc;
+                }
+            }
+        }
+    }
+
+    fn print(&mut self, ch: char) {
+        if self.col >= self.width {
+            self.col = 0;
+            self.line_feed();
+        }
+        self.cells[self.row][self.col] = Cell { ch, fg: self.fg };
+        self.col += 1;
+    }
+
+    fn line_feed(&mut self) {
+        if self.row + 1 < self.height {
+            self.row += 1;
+        } else {
+            self.scroll_up(1);
+        }
+    }
+
+    fn scroll_up(&mut self, lines: usize) {
+        for _ in 0..lines.min(self.height) {
+            self.cells.remove(0);
+            self.cells.push(vec![Cell::BLANK; self.width]);
+        }
+    }
+
+    fn csi(&mut self, params: &str, action: char) {
+        if params.starts_with('?') {
+            // Private modes (cursor visibility, mouse capture, ...) do not affect the screen
+            return;
+        }
+        let args: Vec<usize> = params
+            .split(';')
+            .map(|arg| arg.parse().unwrap_or(0))
+            .collect();
+        let arg = |idx: usize, default: usize| match args.get(idx) {
+            Some(&0) | None => default,
+            Some(&value) => value,
+        };
+        let col = self.col.min(self.width - 1);
+        match action {
+            'H' | 'f' => {
+                self.row = (arg(0, 1) - 1).min(self.height - 1);
+                self.col = (arg(1, 1) - 1).min(self.width - 1);
+            }
+            'A' => self.row = self.row.saturating_sub(arg(0, 1)),
+            'B' => self.row = (self.row + arg(0, 1)).min(self.height - 1),
+            'C' => self.col = (col + arg(0, 1)).min(self.width - 1),
+            'D' => self.col = col.saturating_sub(arg(0, 1)),
+            'E' => (self.col, self.row) = (0, (self.row + arg(0, 1)).min(self.height - 1)),
+            'F' => (self.col, self.row) = (0, self.row.saturating_sub(arg(0, 1))),
+            'G' => self.col = (arg(0, 1) - 1).min(self.width - 1),
+            'd' => self.row = (arg(0, 1) - 1).min(self.height - 1),
+            'J' => {
+                let (rows, from) = match args.first().copied().unwrap_or(0) {
+                    0 => (self.row + 1..self.height, Some((self.row, col..self.width))),
+                    1 => (0..self.row, Some((self.row, 0..col + 1))),
+                    _ => (0..self.height, None),
+                };
+                for row in rows {
+                    self.cells[row].fill(Cell::BLANK);
+                }
+                if let Some((row, cols)) = from {
+                    self.cells[row][cols].fill(Cell::BLANK);
+                }
+            }
+            'K' => {
+                let cols = match args.first().copied().unwrap_or(0) {
+                    0 => col..self.width,
+                    1 => 0..col + 1,
+                    _ => 0..self.width,
+                };
+                self.cells[self.row][cols].fill(Cell::BLANK);
+            }
+            'S' => self.scroll_up(arg(0, 1)),
+            'm' => self.sgr(&args),
+            _ => {}
+        }
+    }
+
+    /// Track the foreground color; other attributes are ignored.
+    fn sgr(&mut self, args: &[usize]) {
+        let mut args = args.iter().copied();
+        while let Some(code) = args.next() {
+            match code {
+                0 | 39 => self.fg = None,
+                30..=37 => self.fg = ansi_color(code - 30),
+                90..=97 => self.fg = ansi_color(code - 90 + 8),
+                38 => match args.next() {
+                    Some(5) => self.fg = args.next().and_then(ansi_color),
+                    Some(2) => {
+                        let mut channel = || args.next().and_then(|c| u8::try_from(c).ok());
+                        self.fg = match (channel(), channel(), channel()) {
+                            (Some(r), Some(g), Some(b)) => Some(Color::Rgb { r, g, b }),
+                            _ => None,
+                        };
+                    }
+                    _ => {}
+                },
+                48 | 58 => match args.next() {
+                    Some(5) => {
+                        args.next();
+                    }
+                    Some(2) => {
+                        args.nth(2);
+                    }
+                    _ => {}
+                },
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Color for a 256-color palette index: a named color for the 16 standard
+/// colors, otherwise an `Ansi256` color.
+fn ansi_color(index: usize) -> Option<Color> {
+    u8::try_from(index).ok().map(Color::from_ansi_index)
+}
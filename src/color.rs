@@ -1,7 +1,15 @@
 pub use crossterm::style::Stylize;
 
+use std::env;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::RwLock;
+
+/// Color choice set with [`set_color_choice`].
+static CHOICE: RwLock<ColorChoice> = RwLock::new(ColorChoice::Auto);
+
 /// Simplified color enum to avoid depending on `crossterm::Color` in user-facing types.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Color {
     Reset,
     Black,
@@ -20,6 +28,16 @@ pub enum Color {
     DarkCyan,
     Grey,
     White,
+    /// A 24-bit color, downgraded to the nearest palette color on terminals
+    /// without truecolor support.
+    Rgb {
+        r: u8,
+        g: u8,
+        b: u8,
+    },
+    /// A color of the 256-color palette: the 16 named colors, a 6x6x6 color
+    /// cube (16-231) and a grayscale ramp (232-255).
+    Ansi256(u8),
 }
 
 impl From<Color> for crossterm::style::Color {
@@ -43,6 +61,631 @@ impl From<Color> for crossterm::style::Color {
             Color::DarkCyan => C::DarkCyan,
             Color::Grey => C::Grey,
             Color::White => C::White,
+            Color::Rgb { r, g, b } => C::Rgb { r, g, b },
+            Color::Ansi256(index) => C::AnsiValue(index),
+        }
+    }
+}
+
+/// The 16 named colors, in ANSI palette order.
+const NAMED: [Color; 16] = [
+    Color::Black,
+    Color::DarkRed,
+    Color::DarkGreen,
+    Color::DarkYellow,
+    Color::DarkBlue,
+    Color::DarkMagenta,
+    Color::DarkCyan,
+    Color::Grey,
+    Color::DarkGrey,
+    Color::Red,
+    Color::Green,
+    Color::Yellow,
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::White,
+];
+
+/// RGB values of the 16 named colors, as xterm draws them.
+const NAMED_RGB: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// Names of the named colors, as displayed and parsed.
+const COLOR_NAMES: [(Color, &str); 17] = [
+    (Color::Reset, "reset"),
+    (Color::Black, "black"),
+    (Color::DarkGrey, "dark_grey"),
+    (Color::Red, "red"),
+    (Color::DarkRed, "dark_red"),
+    (Color::Green, "green"),
+    (Color::DarkGreen, "dark_green"),
+    (Color::Yellow, "yellow"),
+    (Color::DarkYellow, "dark_yellow"),
+    (Color::Blue, "blue"),
+    (Color::DarkBlue, "dark_blue"),
+    (Color::Magenta, "magenta"),
+    (Color::DarkMagenta, "dark_magenta"),
+    (Color::Cyan, "cyan"),
+    (Color::DarkCyan, "dark_cyan"),
+    (Color::Grey, "grey"),
+    (Color::White, "white"),
+];
+
+/// Channel values of the 6x6x6 color cube in the 256-color palette.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+impl Color {
+    /// All named colors, starting with `Reset`; the `Rgb` and `Ansi256`
+    /// variants are left out.
+    ///
+    /// ```rust
+    /// use prettui::color::Color;
+    ///
+    /// let names: Vec<String> = Color::iter().map(|color| color.to_string()).collect();
+    /// assert_eq!(names.len(), 17);
+    /// assert_eq!(names[2], "dark_grey");
+    /// ```
+    pub fn iter() -> impl Iterator<Item = Color> {
+        COLOR_NAMES.into_iter().map(|(color, _)| color)
+    }
+
+    /// Parse a hex color: `#rrggbb` or `#rgb`, with or without the `#`.
+    ///
+    /// ```rust
+    /// use prettui::color::Color;
+    ///
+    /// assert_eq!(Color::from_hex("#1e90ff"), Some(Color::Rgb { r: 30, g: 144, b: 255 }));
+    /// assert_eq!(Color::from_hex("f80"), Some(Color::Rgb { r: 255, g: 136, b: 0 }));
+    /// assert_eq!(Color::from_hex("#12345"), None);
+    /// assert_eq!(Color::from_hex("+1+2+3"), None);
+    /// ```
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        let hex = hex.strip_prefix('#').unwrap_or(hex);
+        if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        let channel = |digits: &str| u8::from_str_radix(digits, 16).ok();
+        let (r, g, b) = match hex.len() {
+            6 => (
+                channel(&hex[0..2])?,
+                channel(&hex[2..4])?,
+                channel(&hex[4..6])?,
+            ),
+            3 => (
+                channel(&hex[0..1])? * 17,
+                channel(&hex[1..2])? * 17,
+                channel(&hex[2..3])? * 17,
+            ),
+            _ => return None,
+        };
+        Some(Color::Rgb { r, g, b })
+    }
+
+    /// Look up a CSS named color such as `dodgerblue`, ignoring case.
+    ///
+    /// ```rust
+    /// use prettui::color::Color;
+    ///
+    /// assert_eq!(Color::from_css_name("DodgerBlue"), Color::from_hex("#1e90ff"));
+    /// assert_eq!(Color::from_css_name("blurple"), None);
+    /// ```
+    pub fn from_css_name(name: &str) -> Option<Self> {
+        CSS_COLORS
+            .iter()
+            .find(|(css, _)| css.eq_ignore_ascii_case(name))
+            .map(|&(_, rgb)| Color::Rgb {
+                r: (rgb >> 16) as u8,
+                g: (rgb >> 8) as u8,
+                b: rgb as u8,
+            })
+    }
+
+    /// The nearest color `depth` can show: truecolor keeps every color, the
+    /// 256-color palette replaces RGB colors, and 16 colors leave only the
+    /// named colors.
+    ///
+    /// ```rust
+    /// use prettui::color::{Color, ColorDepth};
+    ///
+    /// let blue = Color::Rgb { r: 30, g: 144, b: 255 };
+    /// assert_eq!(blue.downgrade(ColorDepth::TrueColor), blue);
+    /// assert_eq!(blue.downgrade(ColorDepth::Ansi256), Color::Ansi256(33));
+    /// assert_eq!(blue.downgrade(ColorDepth::Ansi16), Color::Blue);
+    /// ```
+    pub fn downgrade(self, depth: ColorDepth) -> Self {
+        match (self, depth) {
+            (_, ColorDepth::TrueColor) => self,
+            (Color::Rgb { r, g, b }, ColorDepth::Ansi256) => {
+                Color::Ansi256(nearest_ansi256(r, g, b))
+            }
+            (Color::Ansi256(index), ColorDepth::Ansi16) if index < 16 => {
+                Color::from_ansi_index(index)
+            }
+            (Color::Rgb { .. } | Color::Ansi256(_), ColorDepth::Ansi16) => {
+                let (r, g, b) = self.rgb().unwrap_or_default();
+                NAMED[nearest(&NAMED_RGB, (r, g, b))]
+            }
+            _ => self,
+        }
+    }
+
+    /// Color for a 256-color palette index: one of the 16 named colors, or an
+    /// `Ansi256` color.
+    pub(crate) fn from_ansi_index(index: u8) -> Self {
+        NAMED
+            .get(index as usize)
+            .copied()
+            .unwrap_or(Color::Ansi256(index))
+    }
+
+    /// Index of the color in the 256-color palette; `None` for `Reset` and
+    /// RGB colors.
+    pub(crate) fn ansi_index(self) -> Option<u8> {
+        match self {
+            Color::Ansi256(index) => Some(index),
+            named => NAMED
+                .iter()
+                .position(|&color| color == named)
+                .map(|index| index as u8),
+        }
+    }
+
+    /// RGB value of the color as xterm draws it; `None` for `Reset`.
+    fn rgb(self) -> Option<(u8, u8, u8)> {
+        if let Color::Rgb { r, g, b } = self {
+            return Some((r, g, b));
         }
+        let rgb = match self.ansi_index()? {
+            index @ 0..=15 => NAMED_RGB[index as usize],
+            index @ 16..=231 => {
+                let index = index - 16;
+                let level = |i: u8| CUBE_LEVELS[i as usize];
+                (level(index / 36), level(index / 6 % 6), level(index % 6))
+            }
+            index => {
+                let gray = 8 + 10 * (index - 232);
+                (gray, gray, gray)
+            }
+        };
+        Some(rgb)
     }
 }
+
+/// Named colors are shown in snake case (`dark_grey`), RGB colors as
+/// `#rrggbb` and 256-color palette colors as their index, the forms
+/// [`Color::from_str`] reads back.
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Color::Rgb { r, g, b } => write!(f, "#{:02x}{:02x}{:02x}", r, g, b),
+            Color::Ansi256(index) => write!(f, "{}", index),
+            named => {
+                let name = COLOR_NAMES
+                    .iter()
+                    .find(|&&(color, _)| color == named)
+                    .map_or("reset", |&(_, name)| name);
+                f.write_str(name)
+            }
+        }
+    }
+}
+
+/// Parse a color from a name, a CSS color name, a hex color or a 256-color
+/// palette index. Names ignore case, `_`, `-` and spaces, and accept `gray`
+/// for `grey`, so `dark_grey`, `dark-cyan`, `DarkGray` and `darkgrey` all
+/// parse; they win over CSS names of the same spelling. Hex colors need the
+/// `#` (unlike [`Color::from_hex`]), and palette indices are plain digits from
+/// 0 to 255, so the two are never confused.
+///
+/// ```rust
+/// use prettui::color::Color;
+///
+/// assert_eq!("dark-cyan".parse(), Ok(Color::DarkCyan));
+/// assert_eq!("DarkGray".parse(), Ok(Color::DarkGrey));
+/// assert_eq!("dodgerblue".parse(), Ok(Color::Rgb { r: 30, g: 144, b: 255 }));
+/// assert_eq!("#f80".parse(), Ok(Color::Rgb { r: 255, g: 136, b: 0 }));
+/// assert_eq!("208".parse(), Ok(Color::Ansi256(208)));
+/// assert!("100100".parse::<Color>().is_err());
+/// assert!("+5".parse::<Color>().is_err());
+/// assert!("blurple".parse::<Color>().is_err());
+/// ```
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.trim();
+        let error = || ParseColorError {
+            input: text.to_string(),
+        };
+        if !text.is_empty() && text.bytes().all(|b| b.is_ascii_digit()) {
+            return text.parse().map(Color::Ansi256).map_err(|_| error());
+        }
+        if text.starts_with('#') {
+            return Color::from_hex(text).ok_or_else(error);
+        }
+        let key = text
+            .chars()
+            .filter(|c| !matches!(c, '_' | '-' | ' '))
+            .collect::<String>()
+            .to_ascii_lowercase()
+            .replace("gray", "grey");
+        COLOR_NAMES
+            .iter()
+            .find(|(_, name)| name.replace('_', "") == key)
+            .map(|&(color, _)| color)
+            .or_else(|| Color::from_css_name(text))
+            .ok_or_else(error)
+    }
+}
+
+/// Error returned when [`Color::from_str`] does not recognize a color.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseColorError {
+    input: String,
+}
+
+impl ParseColorError {
+    /// The text that is not a color.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Unknown color '{}' (expected a name such as dark_cyan, a hex color such as #1e90ff or a 256-color index)",
+            self.input
+        )
+    }
+}
+
+impl std::error::Error for ParseColorError {}
+
+/// How many colors a terminal can show.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ColorDepth {
+    /// The 16 named colors.
+    Ansi16,
+    /// The 256-color palette.
+    Ansi256,
+    /// 24-bit RGB colors.
+    TrueColor,
+}
+
+impl ColorDepth {
+    /// Detect the depth of the terminal from the environment: `COLORTERM` set
+    /// to `truecolor` or `24bit` (or Windows Terminal) means truecolor, a
+    /// `TERM` containing `256color` means 256 colors, and anything else means
+    /// 16 colors.
+    pub fn detect() -> Self {
+        let var = |name: &str| env::var(name).unwrap_or_default();
+        let colorterm = var("COLORTERM");
+        let term = var("TERM");
+        if matches!(colorterm.as_str(), "truecolor" | "24bit")
+            || term.ends_with("-direct")
+            || env::var_os("WT_SESSION").is_some()
+        {
+            ColorDepth::TrueColor
+        } else if term.contains("256color") {
+            ColorDepth::Ansi256
+        } else {
+            ColorDepth::Ansi16
+        }
+    }
+}
+
+/// Index of the 256-color palette entry nearest to an RGB color, from the
+/// color cube or the grayscale ramp.
+fn nearest_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    let level = |value: u8| {
+        (0..CUBE_LEVELS.len())
+            .min_by_key(|&index| CUBE_LEVELS[index].abs_diff(value))
+            .unwrap_or_default()
+    };
+    let (ri, gi, bi) = (level(r), level(g), level(b));
+    let cube = (CUBE_LEVELS[ri], CUBE_LEVELS[gi], CUBE_LEVELS[bi]);
+    let average = (r as u16 + g as u16 + b as u16) / 3;
+    let gray_index = (average.saturating_sub(3) / 10).min(23) as u8;
+    let gray = 8 + 10 * gray_index;
+    if distance((gray, gray, gray), (r, g, b)) < distance(cube, (r, g, b)) {
+        232 + gray_index
+    } else {
+        16 + 36 * ri as u8 + 6 * gi as u8 + bi as u8
+    }
+}
+
+/// Index of the color in `palette` nearest to `rgb`.
+fn nearest(palette: &[(u8, u8, u8)], rgb: (u8, u8, u8)) -> usize {
+    (0..palette.len())
+        .min_by_key(|&index| distance(palette[index], rgb))
+        .unwrap_or_default()
+}
+
+/// Squared distance between two RGB colors.
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let channel = |x: u8, y: u8| (x as i32 - y as i32).pow(2) as u32;
+    channel(a.0, b.0) + channel(a.1, b.1) + channel(a.2, b.2)
+}
+
+/// When colors are written to the terminal.
+///
+/// Only colors are affected; bold, dim and other attributes are kept on a
+/// terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorChoice {
+    /// Colors when writing to a terminal, following the environment:
+    /// - a non-empty `NO_COLOR` turns colors off
+    /// - otherwise `FORCE_COLOR` or `CLICOLOR_FORCE`, set to anything but
+    ///   `0`, turns colors on even when output is not a terminal
+    /// - otherwise `TERM=dumb` turns colors off
+    #[default]
+    Auto,
+    /// Always write colors, even when output is not a terminal.
+    Always,
+    /// Never write colors.
+    Never,
+}
+
+/// Set when colors are written, for every thread. `write_output`, prompts
+/// and lists all follow it.
+///
+/// ```rust
+/// use prettui::color::{color_choice, set_color_choice, ColorChoice};
+///
+/// // e.g. for a `--color=never` flag
+/// set_color_choice(ColorChoice::Never);
+/// assert_eq!(color_choice(), ColorChoice::Never);
+/// ```
+pub fn set_color_choice(choice: ColorChoice) {
+    // Colors are left out by the backend writers, so crossterm itself must
+    // not drop them for `NO_COLOR`
+    crossterm::style::force_color_output(true);
+    *CHOICE
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = choice;
+}
+
+/// The color choice set with [`set_color_choice`]; `Auto` by default.
+pub fn color_choice() -> ColorChoice {
+    *CHOICE
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Whether colors are written to a stream, given whether it is a `terminal`.
+pub(crate) fn colors_enabled(terminal: bool) -> bool {
+    match color_choice() {
+        ColorChoice::Always => true,
+        ColorChoice::Never => false,
+        ColorChoice::Auto => {
+            let var = |name: &str| env::var(name).unwrap_or_default();
+            let forced = |name: &str| !matches!(var(name).as_str(), "" | "0");
+            if !var("NO_COLOR").is_empty() {
+                false
+            } else if forced("FORCE_COLOR") || forced("CLICOLOR_FORCE") {
+                true
+            } else {
+                terminal && var("TERM") != "dumb"
+            }
+        }
+    }
+}
+
+/// Colors are written as their [`Display`](fmt::Display) form, with
+/// 256-color palette colors as integers, and read with [`Color::from_str`].
+#[cfg(feature = "serde")]
+impl serde::Serialize for Color {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            Color::Ansi256(index) => serializer.serialize_u8(index),
+            color => serializer.collect_str(&color),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Color {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::{Error, Unexpected, Visitor};
+
+        struct ColorVisitor;
+
+        impl Visitor<'_> for ColorVisitor {
+            type Value = Color;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a color name, a hex color such as \"#1e90ff\" or a 256-color index")
+            }
+
+            fn visit_str<E: Error>(self, text: &str) -> Result<Color, E> {
+                text.parse()
+                    .map_err(|_| E::invalid_value(Unexpected::Str(text), &self))
+            }
+
+            fn visit_u64<E: Error>(self, index: u64) -> Result<Color, E> {
+                u8::try_from(index)
+                    .map(Color::Ansi256)
+                    .map_err(|_| E::invalid_value(Unexpected::Unsigned(index), &self))
+            }
+
+            fn visit_i64<E: Error>(self, index: i64) -> Result<Color, E> {
+                u8::try_from(index)
+                    .map(Color::Ansi256)
+                    .map_err(|_| E::invalid_value(Unexpected::Signed(index), &self))
+            }
+        }
+
+        deserializer.deserialize_any(ColorVisitor)
+    }
+}
+
+/// The CSS named colors as `0xRRGGBB`.
+const CSS_COLORS: [(&str, u32); 148] = [
+    ("aliceblue", 0xf0f8ff),
+    ("antiquewhite", 0xfaebd7),
+    ("aqua", 0x00ffff),
+    ("aquamarine", 0x7fffd4),
+    ("azure", 0xf0ffff),
+    ("beige", 0xf5f5dc),
+    ("bisque", 0xffe4c4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xffebcd),
+    ("blue", 0x0000ff),
+    ("blueviolet", 0x8a2be2),
+    ("brown", 0xa52a2a),
+    ("burlywood", 0xdeb887),
+    ("cadetblue", 0x5f9ea0),
+    ("chartreuse", 0x7fff00),
+    ("chocolate", 0xd2691e),
+    ("coral", 0xff7f50),
+    ("cornflowerblue", 0x6495ed),
+    ("cornsilk", 0xfff8dc),
+    ("crimson", 0xdc143c),
+    ("cyan", 0x00ffff),
+    ("darkblue", 0x00008b),
+    ("darkcyan", 0x008b8b),
+    ("darkgoldenrod", 0xb8860b),
+    ("darkgray", 0xa9a9a9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xa9a9a9),
+    ("darkkhaki", 0xbdb76b),
+    ("darkmagenta", 0x8b008b),
+    ("darkolivegreen", 0x556b2f),
+    ("darkorange", 0xff8c00),
+    ("darkorchid", 0x9932cc),
+    ("darkred", 0x8b0000),
+    ("darksalmon", 0xe9967a),
+    ("darkseagreen", 0x8fbc8f),
+    ("darkslateblue", 0x483d8b),
+    ("darkslategray", 0x2f4f4f),
+    ("darkslategrey", 0x2f4f4f),
+    ("darkturquoise", 0x00ced1),
+    ("darkviolet", 0x9400d3),
+    ("deeppink", 0xff1493),
+    ("deepskyblue", 0x00bfff),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1e90ff),
+    ("firebrick", 0xb22222),
+    ("floralwhite", 0xfffaf0),
+    ("forestgreen", 0x228b22),
+    ("fuchsia", 0xff00ff),
+    ("gainsboro", 0xdcdcdc),
+    ("ghostwhite", 0xf8f8ff),
+    ("gold", 0xffd700),
+    ("goldenrod", 0xdaa520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xadff2f),
+    ("grey", 0x808080),
+    ("honeydew", 0xf0fff0),
+    ("hotpink", 0xff69b4),
+    ("indianred", 0xcd5c5c),
+    ("indigo", 0x4b0082),
+    ("ivory", 0xfffff0),
+    ("khaki", 0xf0e68c),
+    ("lavender", 0xe6e6fa),
+    ("lavenderblush", 0xfff0f5),
+    ("lawngreen", 0x7cfc00),
+    ("lemonchiffon", 0xfffacd),
+    ("lightblue", 0xadd8e6),
+    ("lightcoral", 0xf08080),
+    ("lightcyan", 0xe0ffff),
+    ("lightgoldenrodyellow", 0xfafad2),
+    ("lightgray", 0xd3d3d3),
+    ("lightgreen", 0x90ee90),
+    ("lightgrey", 0xd3d3d3),
+    ("lightpink", 0xffb6c1),
+    ("lightsalmon", 0xffa07a),
+    ("lightseagreen", 0x20b2aa),
+    ("lightskyblue", 0x87cefa),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xb0c4de),
+    ("lightyellow", 0xffffe0),
+    ("lime", 0x00ff00),
+    ("limegreen", 0x32cd32),
+    ("linen", 0xfaf0e6),
+    ("magenta", 0xff00ff),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66cdaa),
+    ("mediumblue", 0x0000cd),
+    ("mediumorchid", 0xba55d3),
+    ("mediumpurple", 0x9370db),
+    ("mediumseagreen", 0x3cb371),
+    ("mediumslateblue", 0x7b68ee),
+    ("mediumspringgreen", 0x00fa9a),
+    ("mediumturquoise", 0x48d1cc),
+    ("mediumvioletred", 0xc71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xf5fffa),
+    ("mistyrose", 0xffe4e1),
+    ("moccasin", 0xffe4b5),
+    ("navajowhite", 0xffdead),
+    ("navy", 0x000080),
+    ("oldlace", 0xfdf5e6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6b8e23),
+    ("orange", 0xffa500),
+    ("orangered", 0xff4500),
+    ("orchid", 0xda70d6),
+    ("palegoldenrod", 0xeee8aa),
+    ("palegreen", 0x98fb98),
+    ("paleturquoise", 0xafeeee),
+    ("palevioletred", 0xdb7093),
+    ("papayawhip", 0xffefd5),
+    ("peachpuff", 0xffdab9),
+    ("peru", 0xcd853f),
+    ("pink", 0xffc0cb),
+    ("plum", 0xdda0dd),
+    ("powderblue", 0xb0e0e6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xff0000),
+    ("rosybrown", 0xbc8f8f),
+    ("royalblue", 0x4169e1),
+    ("saddlebrown", 0x8b4513),
+    ("salmon", 0xfa8072),
+    ("sandybrown", 0xf4a460),
+    ("seagreen", 0x2e8b57),
+    ("seashell", 0xfff5ee),
+    ("sienna", 0xa0522d),
+    ("silver", 0xc0c0c0),
+    ("skyblue", 0x87ceeb),
+    ("slateblue", 0x6a5acd),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xfffafa),
+    ("springgreen", 0x00ff7f),
+    ("steelblue", 0x4682b4),
+    ("tan", 0xd2b48c),
+    ("teal", 0x008080),
+    ("thistle", 0xd8bfd8),
+    ("tomato", 0xff6347),
+    ("turquoise", 0x40e0d0),
+    ("violet", 0xee82ee),
+    ("wheat", 0xf5deb3),
+    ("white", 0xffffff),
+    ("whitesmoke", 0xf5f5f5),
+    ("yellow", 0xffff00),
+    ("yellowgreen", 0x9acd32),
+];
@@ -0,0 +1,295 @@
+//! Non-interactive answers for prompts and lists.
+//!
+//! Scripts and CI jobs can answer prompts without a terminal by installing an
+//! [`Answers`] set. Each prompt is identified by a key derived from its
+//! message: lowercase letters and digits with every other run of characters
+//! replaced by `_`, so `confirm("Continue installation?", ..)` is answered by
+//! the key `continue_installation`. `read_input`, `read_secret_input` and
+//! `read_multiline_input` are keyed by
+//! [`InputConfig::answer_key`](crate::io::input::InputConfig::answer_key),
+//! or else by the prefix and prompt of their `InputConfig`, and lists by
+//! [`ListConfig::answer_key`](crate::list::ListConfig::answer_key).
+//!
+//! Answers come from, in order of precedence:
+//! - environment variables `<PREFIX>_<KEY>` (uppercased), with
+//!   [`Answers::env_prefix`]
+//! - keyed answers given with [`Answers::answer`] or, with the `serde`
+//!   feature, loaded from a TOML or JSON file with `Answers::from_file`
+//! - the prompt's default, with [`Answers::assume_defaults`]
+//!
+//! An answer is checked like typed input, and a rejected answer is an error
+//! rather than a retry. When no answer is found, the prompt is shown as usual
+//! on a terminal; when input is not a terminal, a `NotFound` error names the
+//! missing key instead of waiting for input that never comes.
+//!
+//! List answers are item numbers as shown in the list, or item labels
+//! (ignoring case); multi-select lists take several separated by commas.
+//!
+//! # Example
+//!
+//! ```rust
+//! use prettui::io::answers::Answers;
+//! use prettui::io::prompt::{confirm, read_number, ConfirmConfig, NumberConfig};
+//! use prettui::io::input::InputConfig;
+//!
+//! let answers = Answers::new()
+//!     .answer("continue_installation", "true")
+//!     .answer("Workers", "8")
+//!     .env_prefix("MYTOOL")
+//!     .assume_defaults(true);
+//! answers.install();
+//!
+//! let input_cfg = InputConfig::default();
+//! assert!(confirm("Continue installation?", &ConfirmConfig::default(), &input_cfg)?);
+//! assert_eq!(read_number("Workers", &NumberConfig::default(), &input_cfg)?, 8);
+//! let retries = NumberConfig { default: Some(3), ..Default::default() };
+//! assert_eq!(read_number("Retries", &retries, &input_cfg)?, 3);
+//! # prettui::io::answers::clear();
+//! # std::io::Result::Ok(())
+//! ```
+
+use crate::backend;
+use std::collections::HashMap;
+#[cfg(feature = "serde")]
+use std::fs;
+use std::io;
+#[cfg(feature = "serde")]
+use std::path::Path;
+use std::sync::RwLock;
+
+/// Answers installed with [`Answers::install`].
+static INSTALLED: RwLock<Option<Answers>> = RwLock::new(None);
+
+/// A set of answers for prompts, keyed by prompt.
+#[derive(Debug, Clone, Default)]
+pub struct Answers {
+    values: HashMap<String, String>,
+    env_prefix: Option<String>,
+    assume_defaults: bool,
+}
+
+impl Answers {
+    /// Create an empty answer set; prompts without an answer are shown as usual.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load answers from a `.toml` or `.json` file. Values may be strings,
+    /// numbers, booleans or arrays (for multi-select lists); nested tables
+    /// are flattened, so `[db] host = ".."` answers the key `db_host`.
+    ///
+    /// # Errors
+    /// Returns an `io::Error` if the file cannot be read, has another
+    /// extension, or cannot be parsed.
+    #[cfg(feature = "serde")]
+    pub fn from_file(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path)?;
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => Self::from_toml(&text),
+            Some("json") => Self::from_json(&text),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Unsupported answers file: {}", path.display()),
+            )),
+        }
+    }
+
+    /// Parse answers from a TOML document.
+    ///
+    /// # Errors
+    /// Returns an `InvalidData` error if the document cannot be parsed.
+    #[cfg(feature = "serde")]
+    pub fn from_toml(text: &str) -> io::Result<Self> {
+        let table: toml::Table = text
+            .parse()
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        let mut answers = Self::new();
+        for (key, value) in table {
+            answers.insert_toml(key, value);
+        }
+        Ok(answers)
+    }
+
+    /// Parse answers from a JSON object.
+    ///
+    /// # Errors
+    /// Returns an `InvalidData` error if the text is not a JSON object.
+    #[cfg(feature = "serde")]
+    pub fn from_json(text: &str) -> io::Result<Self> {
+        let value: serde_json::Value = serde_json::from_str(text)?;
+        let serde_json::Value::Object(object) = value else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Answers must be a JSON object",
+            ));
+        };
+        let mut answers = Self::new();
+        for (key, value) in object {
+            answers.insert_json(key, value);
+        }
+        Ok(answers)
+    }
+
+    /// Add the answer for the prompt `key` (a key or the prompt's message).
+    pub fn answer(mut self, key: &str, value: impl Into<String>) -> Self {
+        self.values.insert(answer_key(key), value.into());
+        self
+    }
+
+    /// Also look answers up in the environment variables `<prefix>_<KEY>`;
+    /// these take precedence over other answers.
+    pub fn env_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.env_prefix = Some(prefix.into());
+        self
+    }
+
+    /// Answer prompts that have a default with that default.
+    pub fn assume_defaults(mut self, val: bool) -> Self {
+        self.assume_defaults = val;
+        self
+    }
+
+    /// The answer for the prompt `key` (a key or the prompt's message), if any.
+    pub fn get(&self, key: &str) -> Option<String> {
+        let key = answer_key(key);
+        if key.is_empty() {
+            return None;
+        }
+        self.env_var(&key)
+            .and_then(|var| std::env::var(var).ok())
+            .or_else(|| self.values.get(&key).cloned())
+    }
+
+    /// Install these answers for all prompts and lists, replacing any
+    /// previously installed answers.
+    pub fn install(self) {
+        *INSTALLED
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(self);
+    }
+
+    /// Environment variable holding the answer for the normalized `key`.
+    fn env_var(&self, key: &str) -> Option<String> {
+        let prefix = self.env_prefix.as_deref()?;
+        Some(format!("{}_{}", prefix, key).to_uppercase())
+    }
+
+    #[cfg(feature = "serde")]
+    fn insert_toml(&mut self, key: String, value: toml::Value) {
+        let text = match value {
+            toml::Value::String(text) => text,
+            toml::Value::Table(table) => {
+                for (sub, value) in table {
+                    self.insert_toml(format!("{}_{}", key, sub), value);
+                }
+                return;
+            }
+            toml::Value::Array(items) => items
+                .into_iter()
+                .map(|item| match item {
+                    toml::Value::String(text) => text,
+                    other => other.to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join(","),
+            other => other.to_string(),
+        };
+        self.values.insert(answer_key(&key), text);
+    }
+
+    #[cfg(feature = "serde")]
+    fn insert_json(&mut self, key: String, value: serde_json::Value) {
+        let text = match value {
+            serde_json::Value::String(text) => text,
+            serde_json::Value::Object(object) => {
+                for (sub, value) in object {
+                    self.insert_json(format!("{}_{}", key, sub), value);
+                }
+                return;
+            }
+            serde_json::Value::Array(items) => items
+                .into_iter()
+                .map(|item| match item {
+                    serde_json::Value::String(text) => text,
+                    other => other.to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join(","),
+            serde_json::Value::Null => return,
+            other => other.to_string(),
+        };
+        self.values.insert(answer_key(&key), text);
+    }
+}
+
+/// Remove the installed answers, so every prompt is shown again.
+pub fn clear() {
+    *INSTALLED
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = None;
+}
+
+/// Key identifying the prompt with `message`: lowercase letters and digits,
+/// with every other run of characters replaced by `_`.
+///
+/// ```rust
+/// use prettui::io::answers::answer_key;
+///
+/// assert_eq!(answer_key("Continue installation?"), "continue_installation");
+/// assert_eq!(answer_key("[TEST] Select count (1-10)"), "test_select_count_1_10");
+/// ```
+pub fn answer_key(message: &str) -> String {
+    let mut key = String::new();
+    for c in message.chars() {
+        if c.is_alphanumeric() {
+            key.extend(c.to_lowercase());
+        } else if !key.is_empty() && !key.ends_with('_') {
+            key.push('_');
+        }
+    }
+    key.trim_end_matches('_').to_string()
+}
+
+/// Error for a preset answer to the prompt `key` that was rejected with `problem`.
+pub(crate) fn invalid_answer(key: &str, problem: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        format!("Invalid answer for '{}': {}", answer_key(key), problem),
+    )
+}
+
+/// Preset reply for the prompt `key`, from the installed answers or, if they
+/// assume defaults, from `default`. `Ok(None)` means the prompt should be
+/// shown.
+///
+/// # Errors
+/// Returns a `NotFound` error if answers are installed, none applies and
+/// input is not a terminal.
+pub(crate) fn preset(key: Option<&str>, default: Option<&str>) -> io::Result<Option<String>> {
+    let installed = INSTALLED
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let Some(answers) = installed.as_ref() else {
+        return Ok(None);
+    };
+    if let Some(answer) = key.and_then(|key| answers.get(key)) {
+        return Ok(Some(answer));
+    }
+    if let (true, Some(default)) = (answers.assume_defaults, default) {
+        return Ok(Some(default.to_string()));
+    }
+    if backend::is_interactive() {
+        return Ok(None);
+    }
+    let key = key.map(answer_key).unwrap_or_default();
+    let message = match (key.is_empty(), answers.env_var(&key)) {
+        (true, _) => String::from("No answer for an unnamed prompt and input is not a terminal"),
+        (false, Some(var)) => format!(
+            "No answer for '{}' and input is not a terminal (set {} or add it to the answers)",
+            key, var
+        ),
+        (false, None) => format!("No answer for '{}' and input is not a terminal", key),
+    };
+    Err(io::Error::new(io::ErrorKind::NotFound, message))
+}
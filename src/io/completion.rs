@@ -0,0 +1,299 @@
+//! Tab completion for `read_input`.
+//!
+//! Set [`InputConfig::completer`](crate::io::input::InputConfig::completer) to
+//! any [`Completer`] and pressing Tab in the line editor asks it for
+//! candidates. A single candidate replaces the word being completed; with
+//! several, their common prefix is inserted and the candidates are shown below
+//! the prompt, either as a plain listing ([`CompletionDisplay::Inline`]) or as a
+//! popup that Tab/Shift+Tab and the arrow keys cycle through
+//! ([`CompletionDisplay::Popup`]).
+//!
+//! [`WordCompleter`] completes from a fixed list of words and [`PathCompleter`]
+//! from the file system. Closures `Fn(&str, usize) -> Completion` are
+//! completers too.
+//!
+//! # Example
+//!
+//! ```rust
+//! use prettui::io::completion::{Completer, Completion, WordCompleter};
+//!
+//! let commands = WordCompleter::new(["build", "bench", "check"]);
+//! let completion = commands.complete("cargo be", 8);
+//! assert_eq!(completion.start, 6);
+//! assert_eq!(completion.candidates, vec!["bench"]);
+//!
+//! let hosts = |line: &str, pos: usize| {
+//!     let start = line[..pos].rfind('@').map_or(0, |at| at + 1);
+//!     Completion::new(start, ["db1.internal", "db2.internal"])
+//! };
+//! assert_eq!(hosts.complete("ssh admin@", 10).start, 10);
+//! ```
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf, MAIN_SEPARATOR};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Result of a completion request.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Completion {
+    /// Byte offset in the line where the text replaced by a candidate starts;
+    /// the replaced text runs from here to the cursor.
+    pub start: usize,
+    /// Candidates, in the order they are shown.
+    pub candidates: Vec<String>,
+}
+
+impl Completion {
+    /// Create a completion replacing the text from `start` to the cursor.
+    pub fn new<S: Into<String>>(start: usize, candidates: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            start,
+            candidates: candidates.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// Source of completion candidates for the line editor.
+pub trait Completer: Send + Sync {
+    /// Complete `line` with the cursor at byte offset `pos`.
+    fn complete(&self, line: &str, pos: usize) -> Completion;
+}
+
+impl<F> Completer for F
+where
+    F: Fn(&str, usize) -> Completion + Send + Sync,
+{
+    fn complete(&self, line: &str, pos: usize) -> Completion {
+        self(line, pos)
+    }
+}
+
+impl fmt::Debug for dyn Completer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Completer")
+    }
+}
+
+/// How several candidates are shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum CompletionDisplay {
+    /// List the candidates below the prompt until the next key press.
+    Inline,
+    /// Show a popup below the prompt; Tab/Down and Shift+Tab/Up move through
+    /// the candidates, inserting the highlighted one, and Enter or Esc closes it.
+    #[default]
+    Popup,
+}
+
+/// Completes the whitespace-separated word before the cursor from a fixed
+/// list of words, such as subcommand names or hostnames.
+#[derive(Debug, Clone, Default)]
+pub struct WordCompleter {
+    words: Vec<String>,
+    ignore_case: bool,
+}
+
+impl WordCompleter {
+    /// Create a completer offering `words`.
+    pub fn new<S: Into<String>>(words: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            words: words.into_iter().map(Into::into).collect(),
+            ignore_case: false,
+        }
+    }
+
+    /// Match words regardless of case.
+    pub fn ignore_case(mut self, val: bool) -> Self {
+        self.ignore_case = val;
+        self
+    }
+}
+
+impl Completer for WordCompleter {
+    fn complete(&self, line: &str, pos: usize) -> Completion {
+        let start = word_start(line, pos);
+        let word = &line[start..pos];
+        let candidates = self
+            .words
+            .iter()
+            .filter(|candidate| {
+                if self.ignore_case {
+                    candidate.to_lowercase().starts_with(&word.to_lowercase())
+                } else {
+                    candidate.starts_with(word)
+                }
+            })
+            .cloned()
+            .collect();
+        Completion { start, candidates }
+    }
+}
+
+/// Completes file and directory names, expanding a leading `~` to the home
+/// directory when looking entries up. Directory candidates end with a path
+/// separator so completion can continue into them; hidden entries are only
+/// offered when the typed name starts with a dot.
+#[derive(Debug, Clone, Default)]
+pub struct PathCompleter {
+    whole_line: bool,
+    dirs_only: bool,
+}
+
+impl PathCompleter {
+    /// Create a completer for the whitespace-separated word before the cursor.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Treat all text before the cursor as the path, so it may contain spaces.
+    pub fn whole_line(mut self, val: bool) -> Self {
+        self.whole_line = val;
+        self
+    }
+
+    /// Offer only directories.
+    pub fn dirs_only(mut self, val: bool) -> Self {
+        self.dirs_only = val;
+        self
+    }
+}
+
+impl Completer for PathCompleter {
+    fn complete(&self, line: &str, pos: usize) -> Completion {
+        let start = if self.whole_line {
+            0
+        } else {
+            word_start(line, pos)
+        };
+        let typed = &line[start..pos];
+        let split = typed.rfind(['/', MAIN_SEPARATOR]).map_or(0, |idx| idx + 1);
+        let (dir, name) = typed.split_at(split);
+        let lookup = if dir.is_empty() {
+            PathBuf::from(".")
+        } else {
+            expand_tilde(dir)
+        };
+
+        let Ok(entries) = fs::read_dir(&lookup) else {
+            return Completion {
+                start,
+                candidates: Vec::new(),
+            };
+        };
+        let mut candidates: Vec<String> = entries
+            .filter_map(|entry| {
+                let entry = entry.ok()?;
+                let file_name = entry.file_name().into_string().ok()?;
+                if !file_name.starts_with(name)
+                    || (file_name.starts_with('.') && !name.starts_with('.'))
+                {
+                    return None;
+                }
+                // Follow symlinks so links to directories complete like directories
+                let is_dir = entry.path().is_dir();
+                if self.dirs_only && !is_dir {
+                    return None;
+                }
+                let suffix = if is_dir { "/" } else { "" };
+                Some(format!("{}{}{}", dir, file_name, suffix))
+            })
+            .collect();
+        candidates.sort();
+        Completion { start, candidates }
+    }
+}
+
+/// Expand a leading `~` (alone or followed by a separator) to the home
+/// directory. Other paths are returned unchanged.
+pub(crate) fn expand_tilde(path: &str) -> PathBuf {
+    let rest = match path.strip_prefix('~') {
+        Some(rest) if rest.is_empty() || rest.starts_with(['/', MAIN_SEPARATOR]) => rest,
+        _ => return PathBuf::from(path),
+    };
+    match home_dir() {
+        Some(home) => home.join(rest.trim_start_matches(['/', MAIN_SEPARATOR])),
+        None => PathBuf::from(path),
+    }
+}
+
+/// The user's home directory, from `HOME` (or `USERPROFILE` on Windows).
+fn home_dir() -> Option<PathBuf> {
+    std::env::var_os("HOME")
+        .or_else(|| std::env::var_os("USERPROFILE"))
+        .filter(|home| !home.is_empty())
+        .map(PathBuf::from)
+}
+
+/// Whether `path` can be written by this process: an existing file opens for
+/// appending, a file can be created (and is removed again) in an existing
+/// directory, and a missing path has a writable parent directory.
+pub(crate) fn is_writable(path: &Path) -> bool {
+    match fs::metadata(path) {
+        Ok(meta) if meta.is_dir() => can_create_in(path),
+        Ok(_) => fs::OpenOptions::new().append(true).open(path).is_ok(),
+        Err(_) => {
+            let parent = match path.parent() {
+                Some(parent) if parent.as_os_str().is_empty() => Path::new("."),
+                Some(parent) => parent,
+                None => return false,
+            };
+            parent.is_dir() && is_writable(parent)
+        }
+    }
+}
+
+/// Whether a file can be created in the directory `dir`, probed by creating
+/// and removing an unused file.
+fn can_create_in(dir: &Path) -> bool {
+    static PROBES: AtomicUsize = AtomicUsize::new(0);
+    for _ in 0..8 {
+        let probe = dir.join(format!(
+            ".prettui-probe-{}-{}",
+            std::process::id(),
+            PROBES.fetch_add(1, Ordering::Relaxed)
+        ));
+        match fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&probe)
+        {
+            Ok(_) => {
+                let _ = fs::remove_file(&probe);
+                return true;
+            }
+            Err(err) if err.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(_) => return false,
+        }
+    }
+    false
+}
+
+/// Byte offset where the whitespace-separated word ending at `pos` starts.
+pub(crate) fn word_start(line: &str, pos: usize) -> usize {
+    line[..pos].rfind(char::is_whitespace).map_or(0, |idx| {
+        idx + line[idx..].chars().next().map_or(1, char::len_utf8)
+    })
+}
+
+/// Longest prefix shared by every candidate.
+pub(crate) fn common_prefix(candidates: &[String]) -> String {
+    let Some(first) = candidates.first() else {
+        return String::new();
+    };
+    let mut len = first.len();
+    for candidate in &candidates[1..] {
+        len = first
+            .char_indices()
+            .zip(candidate.chars())
+            .find(|((_, a), b)| a != b)
+            .map_or(len.min(candidate.len()), |((idx, _), _)| idx.min(len));
+    }
+    first[..len].to_string()
+}
@@ -0,0 +1,538 @@
+//! Raw-mode line editor shared by `read_input` and `read_secret_input`.
+//!
+//! Supports emacs-style editing:
+//! - Left/Right or Ctrl+B/Ctrl+F move by character, Alt+B/Alt+F or
+//!   Ctrl+Left/Ctrl+Right move by word, Home/End or Ctrl+A/Ctrl+E jump to
+//!   the start or end of the line
+//! - Backspace/Delete (or Ctrl+H/Ctrl+D) delete a character, Ctrl+W or
+//!   Alt+Backspace delete the previous word, Alt+D the next word
+//! - Ctrl+U and Ctrl+K kill to the start or end of the line; Ctrl+Y yanks the
+//!   last killed text back
+//! - Up/Down or Ctrl+P/Ctrl+N walk through the history
+//! - Tab asks the configured completer for candidates
+//! - Enter accepts the line, Ctrl+C cancels and Ctrl+D on an empty line ends input
+//!
+//! Bracketed paste is enabled while editing, so pasted text is inserted as a
+//! whole, with line breaks turned into spaces, instead of an Enter in it
+//! accepting the line.
+//!
+//! An empty line shows `InputConfig::default` (or else
+//! `InputConfig::placeholder`) dimmed. Enter on an empty line accepts the
+//! default, and Right/End (or Ctrl+F/Ctrl+E) bring it in for editing.
+//!
+//! With a check and `InputConfig::live_validation`, the line is validated on
+//! every key press: the text is colored valid or invalid, the check's message
+//! is shown on a status line below, and Enter only accepts a valid line.
+
+use crate::backend;
+use crate::io::completion::{common_prefix, Completion, CompletionDisplay};
+use crate::io::input::InputConfig;
+use crossterm::{
+    cursor::MoveTo,
+    event::{
+        DisableBracketedPaste, EnableBracketedPaste, Event, KeyCode, KeyEvent, KeyEventKind,
+        KeyModifiers,
+    },
+    execute, queue,
+    style::{Print, PrintStyledContent},
+    terminal::{Clear, ClearType},
+};
+use std::io::{self, Write};
+
+/// Whether typed characters are shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Echo {
+    Visible,
+    Hidden,
+}
+
+/// Validation run on the whole line while it is edited.
+pub(crate) type Check<'a> = &'a dyn Fn(&str) -> Result<(), String>;
+
+/// Maximum number of candidate rows shown below the prompt.
+const MENU_ROWS: usize = 5;
+
+/// Completion candidates shown below the prompt.
+struct Menu {
+    /// Character index where the completed word starts.
+    start: usize,
+    candidates: Vec<String>,
+    /// Candidate currently inserted by cycling through the popup.
+    selected: Option<usize>,
+}
+
+/// What a key press asks the editor to do next.
+enum Outcome {
+    Continue,
+    Accept,
+}
+
+/// State of the line being edited.
+struct LineEditor<'a> {
+    cfg: &'a InputConfig,
+    echo: Echo,
+    buffer: Vec<char>,
+    cursor: usize,
+    /// Text removed by the last kill command, for Ctrl+Y.
+    killed: Vec<char>,
+    /// History snapshot and the entry currently shown, if any.
+    history: Vec<String>,
+    history_pos: Option<usize>,
+    /// The line being typed before the history was entered.
+    draft: Vec<char>,
+    /// Screen position where the input starts.
+    start_col: u16,
+    start_row: u16,
+    /// Completion candidates currently shown, if any.
+    menu: Option<Menu>,
+    /// Live validation of the line, if enabled.
+    check: Option<Check<'a>>,
+    /// Show the validation message even for an empty line, after a rejected Enter.
+    show_problem: bool,
+    /// Editing has ended; only the text itself is drawn.
+    done: bool,
+    /// Set when Enter accepted the default on an empty line.
+    defaulted: bool,
+}
+
+/// Read a line in raw mode after the prompt has been printed.
+///
+/// Accepted visible lines other than an accepted default are added to
+/// `cfg.history`, ignoring errors saving its file. Ctrl+C returns an
+/// `Interrupted` error and Ctrl+D on an empty line an `UnexpectedEof` error.
+/// Visible lines are validated with `check` while typing if
+/// `cfg.live_validation` is set.
+pub(crate) fn read_line(cfg: &InputConfig, echo: Echo, check: Option<Check>) -> io::Result<String> {
+    backend::enable_raw_mode()?;
+    let result = edit(cfg, echo, check);
+    backend::disable_raw_mode()?;
+    // Move to next line
+    writeln!(backend::stdout())?;
+
+    let (line, defaulted) = result?;
+    if echo == Echo::Visible && !defaulted {
+        if let Some(history) = &cfg.history {
+            // The line is kept in memory even if the history file cannot be
+            // written; losing a submitted line would be worse
+            let _ = history.push(line.as_str());
+        }
+    }
+    Ok(line)
+}
+
+/// Edit a line; also returns whether it is the default accepted on an empty line.
+fn edit<'a>(
+    cfg: &'a InputConfig,
+    echo: Echo,
+    check: Option<Check<'a>>,
+) -> io::Result<(String, bool)> {
+    let (start_col, start_row) = backend::cursor_position()?;
+    let history = match (&cfg.history, echo) {
+        (Some(history), Echo::Visible) => history.entries(),
+        _ => Vec::new(),
+    };
+    let mut editor = LineEditor {
+        cfg,
+        echo,
+        buffer: Vec::new(),
+        cursor: 0,
+        killed: Vec::new(),
+        history,
+        history_pos: None,
+        draft: Vec::new(),
+        start_col,
+        start_row,
+        menu: None,
+        check: check.filter(|_| cfg.live_validation && echo == Echo::Visible),
+        show_problem: false,
+        done: false,
+        defaulted: false,
+    };
+    // Show the default or placeholder before the first key press
+    editor.redraw()?;
+
+    // Terminals without bracketed paste ignore the request
+    let _ = execute!(backend::stdout(), EnableBracketedPaste);
+    let result = editor.run();
+    let paste_off = execute!(backend::stdout(), DisableBracketedPaste);
+    // Clear any candidates, ghost text and status line and leave the cursor after the text
+    editor.menu = None;
+    editor.check = None;
+    editor.done = true;
+    editor.cursor = editor.buffer.len();
+    editor.redraw()?;
+    paste_off?;
+    result.map(|line| (line, editor.defaulted))
+}
+
+impl LineEditor<'_> {
+    /// Handle events until the line is accepted.
+    fn run(&mut self) -> io::Result<String> {
+        loop {
+            match backend::read_event()? {
+                // Windows also reports key releases; only presses and repeats edit
+                Event::Key(key) if key.kind != KeyEventKind::Release => {
+                    if let Outcome::Accept = self.handle_key(key)? {
+                        let defaulted = self.buffer.is_empty() && self.default().is_some();
+                        if let (true, Some(default)) = (defaulted, self.default()) {
+                            self.buffer = default.chars().collect();
+                        }
+                        if self.problem().is_none() {
+                            self.defaulted = defaulted;
+                            return Ok(self.buffer.iter().collect());
+                        }
+                        self.show_problem = true;
+                    }
+                }
+                Event::Paste(text) => {
+                    let text = text.replace("\r\n", " ").replace(['\r', '\n'], " ");
+                    self.insert(&text.chars().collect::<Vec<_>>());
+                }
+                _ => {}
+            }
+            self.redraw()?;
+        }
+    }
+
+    /// Message of the live check for the line Enter would accept, if it is rejected.
+    fn problem(&self) -> Option<String> {
+        let check = self.check?;
+        match (self.buffer.is_empty(), self.default()) {
+            (true, Some(default)) => check(default).err(),
+            _ => check(&self.buffer.iter().collect::<String>()).err(),
+        }
+    }
+
+    /// Value accepted by Enter on an empty line.
+    fn default(&self) -> Option<&str> {
+        self.cfg
+            .default
+            .as_deref()
+            .filter(|_| self.echo == Echo::Visible)
+    }
+
+    fn handle_key(&mut self, key: KeyEvent) -> io::Result<Outcome> {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        let alt = key.modifiers.contains(KeyModifiers::ALT);
+
+        // Moving right on an empty line brings the default in for editing
+        let to_end = matches!(key.code, KeyCode::Right | KeyCode::End)
+            || (ctrl && matches!(key.code, KeyCode::Char('e' | 'f')));
+        if to_end && self.buffer.is_empty() {
+            if let Some(default) = self.default() {
+                self.insert(&default.chars().collect::<Vec<_>>());
+                return Ok(Outcome::Continue);
+            }
+        }
+
+        if let Some(menu) = self.menu.take() {
+            if self.cfg.completion_display == CompletionDisplay::Popup {
+                match key.code {
+                    KeyCode::Tab | KeyCode::Down => {
+                        self.cycle(menu, true);
+                        return Ok(Outcome::Continue);
+                    }
+                    KeyCode::BackTab | KeyCode::Up => {
+                        self.cycle(menu, false);
+                        return Ok(Outcome::Continue);
+                    }
+                    // Keep the inserted candidate without submitting the line
+                    KeyCode::Enter | KeyCode::Esc => return Ok(Outcome::Continue),
+                    _ => {}
+                }
+            }
+        }
+
+        match key.code {
+            KeyCode::Enter => return Ok(Outcome::Accept),
+            KeyCode::Tab if self.echo == Echo::Visible => self.complete(),
+            KeyCode::Char('c') if ctrl => {
+                return Err(io::Error::new(io::ErrorKind::Interrupted, "Input canceled"));
+            }
+            KeyCode::Char('d') if ctrl && self.buffer.is_empty() => {
+                return Err(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "EOF while reading input",
+                ));
+            }
+            KeyCode::Char('d') if ctrl => self.delete(self.cursor..self.cursor + 1),
+            KeyCode::Char('h') if ctrl => self.delete(self.cursor.saturating_sub(1)..self.cursor),
+            KeyCode::Char('b') if ctrl => self.cursor = self.cursor.saturating_sub(1),
+            KeyCode::Char('f') if ctrl => self.cursor = (self.cursor + 1).min(self.buffer.len()),
+            KeyCode::Char('a') if ctrl => self.cursor = 0,
+            KeyCode::Char('e') if ctrl => self.cursor = self.buffer.len(),
+            KeyCode::Char('w') if ctrl => self.kill(self.word_start()..self.cursor),
+            KeyCode::Char('u') if ctrl => self.kill(0..self.cursor),
+            KeyCode::Char('k') if ctrl => self.kill(self.cursor..self.buffer.len()),
+            KeyCode::Char('y') if ctrl => self.insert(&self.killed.clone()),
+            KeyCode::Char('p') if ctrl => self.history_step(false),
+            KeyCode::Char('n') if ctrl => self.history_step(true),
+            KeyCode::Char('b') if alt => self.cursor = self.word_start(),
+            KeyCode::Char('f') if alt => self.cursor = self.word_end(),
+            KeyCode::Char('d') if alt => self.kill(self.cursor..self.word_end()),
+            KeyCode::Char(_) if ctrl || alt => {}
+            KeyCode::Char(c) => self.insert(&[c]),
+            KeyCode::Backspace if alt => self.kill(self.word_start()..self.cursor),
+            KeyCode::Backspace => self.delete(self.cursor.saturating_sub(1)..self.cursor),
+            KeyCode::Delete => self.delete(self.cursor..self.cursor + 1),
+            KeyCode::Left if ctrl => self.cursor = self.word_start(),
+            KeyCode::Right if ctrl => self.cursor = self.word_end(),
+            KeyCode::Left => self.cursor = self.cursor.saturating_sub(1),
+            KeyCode::Right => self.cursor = (self.cursor + 1).min(self.buffer.len()),
+            KeyCode::Home => self.cursor = 0,
+            KeyCode::End => self.cursor = self.buffer.len(),
+            KeyCode::Up => self.history_step(false),
+            KeyCode::Down => self.history_step(true),
+            _ => {}
+        }
+        Ok(Outcome::Continue)
+    }
+
+    fn insert(&mut self, chars: &[char]) {
+        self.buffer
+            .splice(self.cursor..self.cursor, chars.iter().copied());
+        self.cursor += chars.len();
+    }
+
+    fn delete(&mut self, range: std::ops::Range<usize>) {
+        let end = range.end.min(self.buffer.len());
+        let start = range.start.min(end);
+        self.buffer.drain(start..end);
+        self.cursor = start;
+    }
+
+    /// Delete `range`, remembering the removed text for Ctrl+Y.
+    fn kill(&mut self, range: std::ops::Range<usize>) {
+        if !range.is_empty() {
+            self.killed = self.buffer[range.clone()].to_vec();
+        }
+        self.delete(range);
+    }
+
+    /// Start of the word before the cursor.
+    fn word_start(&self) -> usize {
+        let mut pos = self.cursor;
+        while pos > 0 && !self.buffer[pos - 1].is_alphanumeric() {
+            pos -= 1;
+        }
+        while pos > 0 && self.buffer[pos - 1].is_alphanumeric() {
+            pos -= 1;
+        }
+        pos
+    }
+
+    /// End of the word after the cursor.
+    fn word_end(&self) -> usize {
+        let len = self.buffer.len();
+        let mut pos = self.cursor;
+        while pos < len && !self.buffer[pos].is_alphanumeric() {
+            pos += 1;
+        }
+        while pos < len && self.buffer[pos].is_alphanumeric() {
+            pos += 1;
+        }
+        pos
+    }
+
+    /// Replace the characters in `range` with `text` and move the cursor after it.
+    fn replace(&mut self, range: std::ops::Range<usize>, text: &str) {
+        self.buffer.splice(range.clone(), text.chars());
+        self.cursor = range.start + text.chars().count();
+    }
+
+    /// Ask the completer for candidates for the word before the cursor.
+    fn complete(&mut self) {
+        let Some(completer) = self.cfg.completer.clone() else {
+            return;
+        };
+        let line: String = self.buffer.iter().collect();
+        let pos: usize = self.buffer[..self.cursor]
+            .iter()
+            .map(|c| c.len_utf8())
+            .sum();
+        let Completion { start, candidates } = completer.complete(&line, pos);
+        let start = line
+            .get(..start.min(pos))
+            .map_or(self.cursor, |prefix| prefix.chars().count());
+
+        match candidates.len() {
+            0 => {}
+            1 => self.replace(start..self.cursor, &candidates[0]),
+            _ => {
+                let prefix = common_prefix(&candidates);
+                if prefix.chars().count() > self.cursor - start {
+                    self.replace(start..self.cursor, &prefix);
+                }
+                self.menu = Some(Menu {
+                    start,
+                    candidates,
+                    selected: None,
+                });
+            }
+        }
+    }
+
+    /// Insert the next (or previous) popup candidate and keep the popup open.
+    fn cycle(&mut self, mut menu: Menu, forward: bool) {
+        let count = menu.candidates.len();
+        let next = match (menu.selected, forward) {
+            (None, true) => 0,
+            (None, false) => count - 1,
+            (Some(idx), true) => (idx + 1) % count,
+            (Some(idx), false) => (idx + count - 1) % count,
+        };
+        menu.selected = Some(next);
+        let candidate = menu.candidates[next].clone();
+        self.replace(menu.start..self.cursor, &candidate);
+        self.menu = Some(menu);
+    }
+
+    /// Show the previous (`forward == false`) or next history entry. Moving
+    /// past the newest entry restores the line that was being typed.
+    fn history_step(&mut self, forward: bool) {
+        if self.history.is_empty() {
+            return;
+        }
+        let next = match (self.history_pos, forward) {
+            (None, false) => {
+                self.draft = self.buffer.clone();
+                Some(self.history.len() - 1)
+            }
+            (None, true) => return,
+            (Some(pos), false) => Some(pos.saturating_sub(1)),
+            (Some(pos), true) if pos + 1 < self.history.len() => Some(pos + 1),
+            (Some(_), true) => None,
+        };
+        self.history_pos = next;
+        self.buffer = match next {
+            Some(pos) => self.history[pos].chars().collect(),
+            None => std::mem::take(&mut self.draft),
+        };
+        self.cursor = self.buffer.len();
+    }
+
+    /// Redraw the input after the prompt and place the cursor.
+    fn redraw(&mut self) -> io::Result<()> {
+        if self.echo == Echo::Hidden {
+            return Ok(());
+        }
+        let mut stdout = backend::stdout();
+        let (width, height) = backend::size()?;
+        let (width, height) = (width.max(1) as usize, height.max(1) as usize);
+        let text: String = self.buffer.iter().collect();
+        let problem = self.problem();
+        let style = match (&self.check, &problem) {
+            (None, _) => self.cfg.input_text_color,
+            (Some(_), None) => self.cfg.valid_color,
+            (Some(_), Some(_)) => self.cfg.invalid_color,
+        };
+
+        queue!(
+            stdout,
+            MoveTo(self.start_col, self.start_row),
+            Clear(ClearType::FromCursorDown),
+            PrintStyledContent(style.apply(text))
+        )?;
+        let ghost = self.default().or(self.cfg.placeholder.as_deref());
+        if let (true, false, Some(ghost)) = (self.buffer.is_empty(), self.done, ghost) {
+            // Stay on the input row so the cursor can sit before the ghost text
+            let room = width.saturating_sub(self.start_col as usize + 1);
+            let ghost: String = ghost.chars().take(room).collect();
+            queue!(
+                stdout,
+                PrintStyledContent(self.cfg.input_text_color.dim().apply(ghost))
+            )?;
+        }
+        let end = self.start_col as usize + self.buffer.len();
+        if !self.buffer.is_empty() && end % width == 0 {
+            // Leave the pending wrap so the cursor can sit on the next row
+            queue!(stdout, Print("\r\n"))?;
+        }
+        let mut status_lines = 0;
+        if let Some(problem) = problem.filter(|_| self.show_problem || !self.buffer.is_empty()) {
+            let message: String = problem.chars().take(width - 1).collect();
+            queue!(
+                stdout,
+                Print("\r\n"),
+                PrintStyledContent(self.cfg.invalid_color.apply(message))
+            )?;
+            status_lines = 1;
+        }
+        let menu_lines = self.draw_menu(&mut stdout, width)?;
+        // Text running past the bottom of the screen scrolled it up
+        let overflow = (self.start_row as usize + end / width + status_lines + menu_lines)
+            .saturating_sub(height - 1);
+        self.start_row = (self.start_row as usize).saturating_sub(overflow) as u16;
+
+        let pos = self.start_col as usize + self.cursor;
+        queue!(
+            stdout,
+            MoveTo(
+                (pos % width) as u16,
+                (self.start_row as usize + pos / width) as u16
+            )
+        )?;
+        stdout.flush()
+    }
+
+    /// Draw the completion candidates below the input as a grid of cells,
+    /// paged like the list chooser. Returns the number of lines drawn.
+    fn draw_menu(&self, stdout: &mut impl Write, width: usize) -> io::Result<usize> {
+        let Some(menu) = &self.menu else {
+            return Ok(0);
+        };
+        let popup = self.cfg.completion_display == CompletionDisplay::Popup;
+        let longest = menu
+            .candidates
+            .iter()
+            .map(|candidate| candidate.chars().count())
+            .max()
+            .unwrap_or(0);
+        let cell_width = (longest + 2).min(width);
+        let per_row = (width / cell_width.max(1)).max(1);
+        let rows = menu.candidates.len().div_ceil(per_row);
+        // Show the page holding the selected candidate
+        let first_row = menu
+            .selected
+            .map_or(0, |idx| idx / per_row / MENU_ROWS * MENU_ROWS);
+        let shown = first_row..(first_row + MENU_ROWS).min(rows);
+
+        let mut lines = 0;
+        for row in shown.clone() {
+            queue!(stdout, Print("\r\n"))?;
+            lines += 1;
+            for idx in row * per_row..((row + 1) * per_row).min(menu.candidates.len()) {
+                let style = if menu.selected == Some(idx) {
+                    self.cfg.completion_highlight_color
+                } else {
+                    self.cfg.prompt_color
+                };
+                let text: String = menu.candidates[idx].chars().take(cell_width).collect();
+                queue!(
+                    stdout,
+                    PrintStyledContent(style.apply(format!(
+                        "{:<width$}",
+                        text,
+                        width = cell_width
+                    )))
+                )?;
+            }
+        }
+        if rows > shown.len() {
+            let status = match menu.selected {
+                Some(idx) if popup => format!("({}/{})", idx + 1, menu.candidates.len()),
+                _ => format!(
+                    "(+{} more)",
+                    menu.candidates.len() - shown.end.min(rows) * per_row
+                ),
+            };
+            queue!(
+                stdout,
+                Print("\r\n"),
+                PrintStyledContent(self.cfg.prompt_color.apply(status))
+            )?;
+            lines += 1;
+        }
+        Ok(lines)
+    }
+}
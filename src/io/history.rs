@@ -0,0 +1,178 @@
+//! Input history for the line editor used by `read_input`.
+//!
+//! A [`History`] is a shared handle: clones refer to the same entries, so one
+//! history can be put in several [`InputConfig`](crate::io::input::InputConfig)s
+//! and still be inspected afterwards. A history created with
+//! [`History::with_file`] loads its entries from a file and saves every new
+//! entry back to it, one entry per line.
+//!
+//! # Example
+//!
+//! ```rust
+//! use prettui::io::history::History;
+//!
+//! let history = History::new().max_entries(2);
+//! history.push("first")?;
+//! history.push("second")?;
+//! history.push("second")?; // repeated entries are stored once
+//! history.push("third")?;
+//! assert_eq!(history.entries(), vec!["second", "third"]);
+//!
+//! let path = std::env::temp_dir().join("prettui-history-doctest");
+//! let saved = History::with_file(&path)?;
+//! saved.clear();
+//! saved.push("ls -la")?;
+//! assert_eq!(History::with_file(&path)?.entries(), vec!["ls -la"]);
+//! # std::fs::remove_file(&path)?;
+//! # std::io::Result::Ok(())
+//! ```
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Default maximum number of entries kept by a history.
+const DEFAULT_MAX_ENTRIES: usize = 1000;
+
+/// A shared list of previously entered lines, oldest first.
+#[derive(Debug, Clone)]
+pub struct History {
+    inner: Arc<Mutex<Inner>>,
+}
+
+#[derive(Debug)]
+struct Inner {
+    entries: Vec<String>,
+    max_entries: usize,
+    path: Option<PathBuf>,
+}
+
+impl Default for History {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl History {
+    /// Create an empty in-memory history.
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(Inner {
+                entries: Vec::new(),
+                max_entries: DEFAULT_MAX_ENTRIES,
+                path: None,
+            })),
+        }
+    }
+
+    /// Create a history persisted to `path`, loading any entries it already
+    /// holds. A missing file is treated as an empty history.
+    ///
+    /// # Errors
+    /// Returns an `io::Error` if the file exists but cannot be read.
+    pub fn with_file(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let entries = match fs::read_to_string(&path) {
+            Ok(text) => text
+                .lines()
+                .filter(|line| !line.is_empty())
+                .map(String::from)
+                .collect(),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(err) => return Err(err),
+        };
+        let history = Self::new();
+        {
+            let mut inner = history.lock();
+            inner.entries = entries;
+            inner.path = Some(path);
+            inner.truncate();
+        }
+        Ok(history)
+    }
+
+    /// Set the maximum number of entries kept; older entries are dropped.
+    pub fn max_entries(self, val: usize) -> Self {
+        {
+            let mut inner = self.lock();
+            inner.max_entries = val;
+            inner.truncate();
+        }
+        self
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Inner> {
+        self.inner
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Append an entry. Empty entries and repeats of the latest entry are
+    /// ignored. Persisted histories are saved to their file.
+    ///
+    /// # Errors
+    /// Returns an `io::Error` if the history file cannot be written.
+    pub fn push(&self, entry: impl Into<String>) -> io::Result<()> {
+        let entry = entry.into();
+        let mut inner = self.lock();
+        if entry.trim().is_empty() || inner.entries.last() == Some(&entry) {
+            return Ok(());
+        }
+        inner.entries.push(entry);
+        inner.truncate();
+        inner.save()
+    }
+
+    /// All entries, oldest first.
+    pub fn entries(&self) -> Vec<String> {
+        self.lock().entries.clone()
+    }
+
+    /// Number of entries.
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    /// Whether the history has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.lock().entries.is_empty()
+    }
+
+    /// Remove every entry (the history file is rewritten on the next push or save).
+    pub fn clear(&self) {
+        self.lock().entries.clear();
+    }
+
+    /// File the history is persisted to, if any.
+    pub fn path(&self) -> Option<PathBuf> {
+        self.lock().path.clone()
+    }
+
+    /// Write the entries to the history file; does nothing for in-memory histories.
+    ///
+    /// # Errors
+    /// Returns an `io::Error` if the file cannot be written.
+    pub fn save(&self) -> io::Result<()> {
+        self.lock().save()
+    }
+}
+
+impl Inner {
+    fn truncate(&mut self) {
+        let excess = self.entries.len().saturating_sub(self.max_entries);
+        self.entries.drain(..excess);
+    }
+
+    fn save(&self) -> io::Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)?;
+        }
+        let mut text = self.entries.join("\n");
+        text.push('\n');
+        fs::write(path, text)
+    }
+}
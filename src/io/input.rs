@@ -2,26 +2,39 @@
 //!
 //! This module provides:
 //! - `InputConfig`: customize prompt appearance, colors, wrapping, and indentation.
-//! - `read_input`: read a single line from stdin with styling and EOF handling.
+//! - `read_input`: read a single line with styling, line editing, history and EOF handling.
 //! - `read_multiline_input`: read multiple lines until a terminator is entered, showing prompt only once.
 //! - `wrap_text`: word-wrap long strings into lines of a specified width.
 //! - `read_secret_input`: read a secret line of input without echoing to the terminal.
 //!
+//! On a terminal, `read_input` and `read_secret_input` use a line editor with
+//! emacs-style keys: arrows, Home/End, Ctrl+A/E/B/F, Alt+B/F, Ctrl+W, Ctrl+U,
+//! Ctrl+K, Ctrl+Y, Up/Down (or Ctrl+P/N) to recall entries from
+//! `InputConfig::history`, and Tab to complete with `InputConfig::completer`.
+//! Lines can be checked with `InputConfig::validator`; see [`crate::io::validate`].
+//! With `InputConfig::live_validation`, the check runs on every key press and
+//! its message is shown below the input until the line is valid.
+//! `InputConfig::default` is returned for an empty line and, like
+//! `InputConfig::placeholder`, shown dimmed until the user starts typing.
+//!
 //! # Full Example
 //!
-//! ```rust
-//! use prettui::io::input::{InputConfig, read_input, read_multiline_input};
+//! ```rust,no_run
+//! use prettui::io::input::{InputConfig, read_input, read_multiline_input, read_secret_input};
+//! use prettui::io::history::History;
 //! use prettui::color::Color;
 //!
 //! fn main() -> std::io::Result<()> {
 //!     let cfg = InputConfig {
 //!         prefix: String::from("[TEST] "),
 //!         prompt: String::from(">> "),
-//!         prefix_color: Color::Magenta,
-//!         prompt_color: Color::Cyan,
-//!         input_text_color: Color::White,
+//!         prefix_color: Color::Magenta.into(),
+//!         prompt_color: Color::Cyan.into(),
+//!         input_text_color: Color::White.into(),
 //!         max_chars_per_line: 80,
 //!         indent_level: 2,
+//!         history: Some(History::with_file("/tmp/prettui-history")?),
+//!         ..Default::default()
 //!     };
 //!
 //!     // Single-line input
@@ -40,70 +53,166 @@
 //! }
 //!```
 
-use crate::color::Color;
+use crate::backend;
+use crate::io::answers;
+use crate::io::completion::{Completer, CompletionDisplay};
+use crate::io::editor::{self, Check, Echo};
+use crate::io::history::History;
+use crate::io::prompt::print_error;
+use crate::io::validate::Validator;
+use crate::style::Style;
+use crate::theme;
 use crossterm::{
-    style::{Print, PrintStyledContent, ResetColor, SetForegroundColor, Stylize},
+    style::{Attribute, Print, PrintStyledContent, SetAttribute, SetStyle},
     ExecutableCommand,
 };
-use std::io::{self, BufRead, BufReader, Write};
+use std::io::{self, Write};
+use std::sync::Arc;
 
 /// Configuration for reading input from the user.
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct InputConfig {
     /// Text to display before the prompt (e.g., a label).
     pub prefix: String,
     /// The prompt string shown before reading input (e.g., `"→ "`).
     pub prompt: String,
-    /// Color for the prefix text.
-    pub prefix_color: Color,
-    /// Color for the prompt text.
-    pub prompt_color: Color,
-    /// Color for the user’s input text.
-    pub input_text_color: Color,
+    /// Style for the prefix text.
+    pub prefix_color: Style,
+    /// Style for the prompt text.
+    pub prompt_color: Style,
+    /// Style for the user’s input text.
+    pub input_text_color: Style,
     /// Maximum number of characters per line before wrapping.
     pub max_chars_per_line: usize,
     /// Number of spaces to indent before printing the prompt.
     pub indent_level: usize,
+    /// Lines entered with `read_input` are added here and can be recalled
+    /// with Up/Down; a history file that cannot be saved does not fail the
+    /// input. `None` disables history.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub history: Option<History>,
+    /// Consulted when Tab is pressed in `read_input`. `None` disables completion.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub completer: Option<Arc<dyn Completer>>,
+    /// How several completion candidates are shown.
+    pub completion_display: CompletionDisplay,
+    /// Style of the highlighted candidate in the completion popup.
+    pub completion_highlight_color: Style,
+    /// Checked against each line read by `read_input`; rejected lines are
+    /// reported and the prompt is shown again. `None` accepts any line.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub validator: Option<Arc<dyn Validator<str>>>,
+    /// If true, `read_input` and the prompts validate the line on every key
+    /// press on a terminal, showing the message below the input and only
+    /// accepting a valid line on Enter.
+    pub live_validation: bool,
+    /// Style of the input text while it passes live validation.
+    pub valid_color: Style,
+    /// Style of the input text and message while it fails live validation,
+    /// and of error messages for rejected input.
+    pub invalid_color: Style,
+    /// Value returned when the user presses Enter on an empty line; shown
+    /// dimmed in the line editor, where Right or End bring it in for editing.
+    pub default: Option<String>,
+    /// Hint shown dimmed on an empty line when there is no default; it is
+    /// never returned as input.
+    pub placeholder: Option<String>,
+    /// Key of the input's preset answer (see [`crate::io::answers`]). `None`
+    /// keys it by the prefix and prompt, which then need letters or digits:
+    /// the default prompt `>> ` alone cannot be answered.
+    pub answer_key: Option<String>,
 }
 
 impl Default for InputConfig {
+    /// Returns a default configuration styled by the installed
+    /// [`Theme`](crate::theme::Theme).
     fn default() -> Self {
+        let theme = theme::current();
         Self {
             prefix: String::new(),
             prompt: String::from(">> "),
-            prefix_color: Color::Blue,
-            prompt_color: Color::White,
-            input_text_color: Color::White,
+            prefix_color: theme.prefix,
+            prompt_color: theme.prompt,
+            input_text_color: Style::new(),
             max_chars_per_line: 80,
             indent_level: 0,
+            history: None,
+            completer: None,
+            completion_display: CompletionDisplay::Popup,
+            completion_highlight_color: theme.highlight,
+            validator: None,
+            live_validation: true,
+            valid_color: theme.success,
+            invalid_color: theme.error,
+            default: None,
+            placeholder: None,
+            answer_key: None,
         }
     }
 }
 
-/// Reads a line of input from stdin using the provided configuration.
+/// Reads a line of input using the provided configuration.
+///
+/// On a terminal the line can be edited and recalled from `cfg.history`;
+/// when input is not a terminal (e.g. piped), a plain line is read. An empty
+/// line yields `cfg.default`, if set. Lines rejected by `cfg.validator` are
+/// reported and read again; on a terminal with `cfg.live_validation`, they
+/// are flagged while typing instead. A preset answer (see
+/// [`crate::io::answers`]) is used without reading input.
+///
+/// # Errors
+/// Returns an `UnexpectedEof` error at end of input, an `Interrupted` error
+/// if the user presses Ctrl+C, an error if a preset answer is rejected or
+/// missing without a terminal, or any error from the terminal.
 pub fn read_input(cfg: &InputConfig) -> io::Result<String> {
-    let mut stdout = io::stdout();
-    // Indentation
-    if cfg.indent_level > 0 {
-        let indent = " ".repeat(cfg.indent_level);
-        stdout.execute(Print(indent))?;
+    let check = |line: &str| cfg.validator.as_ref().map_or(Ok(()), |v| v.validate(line));
+    let key = answer_message(cfg);
+    if let Some(answer) = answers::preset(Some(&key), cfg.default.as_deref())? {
+        print_prompt(&mut backend::stdout(), cfg)?;
+        writeln!(backend::stdout(), "{}", answer)?;
+        return match check(&answer) {
+            Ok(()) => Ok(answer),
+            Err(problem) => Err(answers::invalid_answer(&key, &problem)),
+        };
     }
-    // Prefix
-    if !cfg.prefix.is_empty() {
-        stdout.execute(PrintStyledContent(
-            cfg.prefix.clone().with(cfg.prefix_color.into()),
-        ))?;
-    }
-    // Prompt
-    stdout.execute(PrintStyledContent(
-        cfg.prompt.clone().with(cfg.prompt_color.into()),
-    ))?;
-    stdout.flush()?;
-    // Read
-    stdout.execute(SetForegroundColor(cfg.input_text_color.into()))?;
+
+    loop {
+        print_prompt(&mut backend::stdout(), cfg)?;
+        let line = read_reply(cfg, Some(&check))?;
+        match check(&line) {
+            Ok(()) => return Ok(line),
+            Err(problem) => print_error(&problem, cfg)?,
+        }
+    }
+}
+
+/// Read the reply to a prompt that has already been printed: with the line
+/// editor when input and output are terminals, or as a plain line otherwise.
+/// An empty line yields `cfg.default`, if set. `check` is run while typing in
+/// the line editor; callers still validate the returned line.
+pub(crate) fn read_reply(cfg: &InputConfig, check: Option<Check>) -> io::Result<String> {
+    if backend::is_terminal() {
+        return editor::read_line(cfg, Echo::Visible, check);
+    }
+    let line = read_plain_line(cfg)?;
+    match &cfg.default {
+        Some(default) if line.is_empty() => Ok(default.clone()),
+        _ => Ok(line),
+    }
+}
+
+/// Read a line of cooked input without its line ending.
+fn read_plain_line(cfg: &InputConfig) -> io::Result<String> {
+    let mut stdout = backend::stdout();
+    stdout.execute(SetStyle(cfg.input_text_color.into()))?;
     let mut buf = String::new();
-    let bytes = io::stdin().read_line(&mut buf)?;
-    stdout.execute(ResetColor)?;
+    let bytes = backend::read_line(&mut buf)?;
+    stdout.execute(SetAttribute(Attribute::Reset))?;
     if bytes == 0 {
         return Err(io::Error::new(
             io::ErrorKind::UnexpectedEof,
@@ -121,32 +230,36 @@ pub fn read_input(cfg: &InputConfig) -> io::Result<String> {
 
 /// Reads multiple lines of input until the `terminator` line is entered.
 /// Displays the prompt only once; subsequent lines show no prompt.
+/// A preset answer (see [`crate::io::answers`]) is returned without reading input.
 pub fn read_multiline_input(cfg: &InputConfig, terminator: &str) -> io::Result<String> {
-    let mut stdout = io::stdout();
+    if let Some(answer) = answers::preset(Some(&answer_message(cfg)), None)? {
+        return Ok(answer);
+    }
+    let mut stdout = backend::stdout();
     // Print initial prompt line
     if cfg.indent_level > 0 {
         let indent = " ".repeat(cfg.indent_level);
         stdout.execute(Print(indent.clone()))?;
     }
     if !cfg.prefix.is_empty() {
-        stdout.execute(PrintStyledContent(
-            cfg.prefix.clone().with(cfg.prefix_color.into()),
-        ))?;
+        stdout.execute(PrintStyledContent(cfg.prefix_color.apply(&cfg.prefix)))?;
     }
     let header = format!("{} (end with '{}' on new line)\n", cfg.prompt, terminator);
-    stdout.execute(PrintStyledContent(header.with(cfg.prompt_color.into())))?;
+    stdout.execute(PrintStyledContent(cfg.prompt_color.apply(header)))?;
     stdout.flush()?;
 
     // Read raw lines without prompt
-    let stdin = io::stdin();
-    let reader = BufReader::new(stdin.lock());
     let mut lines = Vec::new();
-    for line in reader.lines() {
-        let input = line?;
+    loop {
+        let mut line = String::new();
+        if backend::read_line(&mut line)? == 0 {
+            break;
+        }
+        let input = line.trim_end_matches(['\n', '\r']);
         if input.trim() == terminator {
             break;
         }
-        lines.push(input);
+        lines.push(input.to_string());
     }
     Ok(lines.join("\n"))
 }
@@ -174,67 +287,46 @@ pub(crate) fn wrap_text(text: &str, max_width: usize) -> Vec<String> {
 /// This function:
 /// 1. Applies indentation and prints the styled prompt once.
 /// 2. Enables raw mode to suppress echo.
-/// 3. Reads key events until Enter is pressed, collecting characters.
+/// 3. Reads key events until Enter is pressed, with the same editing keys as
+///    `read_input` (history is never used).
 /// 4. Disables raw mode and moves to a new line.
 /// 5. Returns the entered string (without newline).
 ///
+/// When input is not a terminal (e.g. piped), a plain line is read instead.
+///
 /// # Errors
-/// Returns an `io::Error` if terminal manipulation or reading fails.
-
+/// Returns an `Interrupted` error on Ctrl+C, or an `io::Error` if terminal
+/// manipulation or reading fails.
 pub fn read_secret_input(cfg: &InputConfig) -> io::Result<String> {
-    use crossterm::{
-        event::{self, Event, KeyCode, KeyEvent, KeyModifiers},
-        style::{Print, PrintStyledContent},
-        terminal::{disable_raw_mode, enable_raw_mode},
-        ExecutableCommand,
-    };
-    use std::io::{self, Write};
+    if let Some(answer) = answers::preset(Some(&answer_message(cfg)), None)? {
+        print_prompt(&mut backend::stdout(), cfg)?;
+        writeln!(backend::stdout())?;
+        return Ok(answer);
+    }
+    print_prompt(&mut backend::stdout(), cfg)?;
+    if !backend::is_interactive() {
+        return read_plain_line(cfg);
+    }
+    editor::read_line(cfg, Echo::Hidden, None)
+}
+
+/// Message keying `cfg`'s preset answer: its answer key, or else its prefix
+/// and prompt.
+fn answer_message(cfg: &InputConfig) -> String {
+    cfg.answer_key
+        .clone()
+        .unwrap_or_else(|| format!("{}{}", cfg.prefix, cfg.prompt))
+}
 
-    // Print styled prompt
-    let mut stdout = io::stdout();
+/// Print the indentation, prefix and prompt of `cfg`.
+fn print_prompt(stdout: &mut impl Write, cfg: &InputConfig) -> io::Result<()> {
     if cfg.indent_level > 0 {
         let indent = " ".repeat(cfg.indent_level);
-        // <-- use Print for a plain string
         stdout.execute(Print(indent))?;
     }
     if !cfg.prefix.is_empty() {
-        stdout.execute(PrintStyledContent(
-            cfg.prefix.clone().with(cfg.prefix_color.into()),
-        ))?;
-    }
-    stdout.execute(PrintStyledContent(
-        cfg.prompt.clone().with(cfg.prompt_color.into()),
-    ))?;
-    stdout.flush()?;
-
-    // Enable raw mode (suppress echo)
-    enable_raw_mode()?;
-    let mut input = String::new();
-    loop {
-        // Read next key event, ignoring the extra fields
-        if let Event::Key(KeyEvent {
-            code, modifiers, ..
-        }) = event::read()?
-        {
-            // Ctrl+C -> cancel input
-            if code == KeyCode::Char('c') && modifiers.contains(KeyModifiers::CONTROL) {
-                disable_raw_mode()?;
-                println!();
-                return Err(io::Error::new(io::ErrorKind::Interrupted, "Input canceled"));
-            }
-            match code {
-                KeyCode::Enter => break,
-                KeyCode::Char(c) => input.push(c),
-                KeyCode::Backspace => {
-                    input.pop();
-                }
-                _ => {}
-            }
-        }
+        stdout.execute(PrintStyledContent(cfg.prefix_color.apply(&cfg.prefix)))?;
     }
-    // Restore terminal
-    disable_raw_mode()?;
-    // Move to next line
-    println!();
-    Ok(input)
+    stdout.execute(PrintStyledContent(cfg.prompt_color.apply(&cfg.prompt)))?;
+    stdout.flush()
 }
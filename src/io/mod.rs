@@ -1,6 +1,11 @@
+pub mod answers;
+pub mod completion;
+mod editor;
+pub mod history;
 pub mod input;
 pub mod output;
 pub mod prompt;
+pub mod validate;
 
 pub use input::*;
 pub use output::*;
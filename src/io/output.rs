@@ -2,8 +2,8 @@
 //!
 //! This struct controls how messages are formatted:
 //! - `prefix`: text shown before each message (e.g., a label)
-//! - `prefix_color`: color applied to the prefix and log level tag
-//! - `text_color`: color applied to the message body
+//! - `prefix_color`: style (colors and attributes) of the prefix and log level tag
+//! - `text_color`: style of the message body
 //! - `log_level`: optional tag (e.g., INFO, WARN) displayed before the message
 //! - `indent_level`: number of spaces to indent each line
 //! - `max_chars_per_line`: maximum width before wrapping occurs
@@ -13,6 +13,7 @@
 //! ```rust
 //! use prettui::io::output::{OutputConfig, write_output};
 //! use prettui::color::Color;
+//! use prettui::style::Style;
 //!
 //! fn main() -> std::io::Result<()> {
 //!     // Example 1: simple message with default config
@@ -22,46 +23,55 @@
 //!     // Example 2: with prefix and indent
 //!     let mut cfg2 = OutputConfig::default();
 //!     cfg2.prefix = String::from("[App] ");
-//!     cfg2.prefix_color = Color::Blue;
+//!     cfg2.prefix_color = Color::Blue.into();
 //!     cfg2.indent_level = 4;
 //!     write_output(&cfg2, "Indented message with a prefix.")?;
 //!
-//!     // Example 3: with log level tag and custom text color
+//!     // Example 3: with log level tag and custom text style
 //!     let mut cfg3 = OutputConfig::default();
 //!     cfg3.log_level = Some(String::from("INFO"));
-//!     cfg3.text_color = Color::Green;
+//!     cfg3.text_color = Color::Green.into();
 //!     cfg3.prefix = String::from("[Server] ");
-//!     cfg3.prefix_color = Color::Magenta;
+//!     cfg3.prefix_color = Style::new().fg(Color::Magenta).bold();
 //!     write_output(&cfg3, "Server started on port 8080.")?;
 //!
 //!     Ok(())
 //! }
 //! ```
 
-use crate::color::Color;
+use crate::backend;
 use crate::io::input::wrap_text;
+use crate::style::Style;
+use crate::theme;
 use crossterm::{
+    style::{Print, PrintStyledContent},
     ExecutableCommand,
-    style::{Print, PrintStyledContent, Stylize},
 };
 use std::io::{self, Write};
 
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct OutputConfig {
     pub prefix: String,
-    pub prefix_color: Color,
-    pub text_color: Color,
+    pub prefix_color: Style,
+    pub text_color: Style,
     pub log_level: Option<String>,
     pub indent_level: usize,
     pub max_chars_per_line: usize,
 }
 
 impl Default for OutputConfig {
+    /// Returns a default configuration styled by the installed
+    /// [`Theme`](crate::theme::Theme).
     fn default() -> Self {
         Self {
             prefix: String::new(),
-            prefix_color: Color::Green,
-            text_color: Color::White,
+            prefix_color: theme::current().prefix,
+            text_color: Style::new(),
             log_level: None,
             indent_level: 0,
             max_chars_per_line: 80,
@@ -80,10 +90,14 @@ impl Default for OutputConfig {
 ///    - message text, styled with `cfg.text_color`.
 /// 3. Prints a newline after each line and flushes stdout at the end.
 ///
+/// When stdout is not a terminal (e.g. redirected to a file), the colors are
+/// left out and only the plain text is written. Colors also follow the
+/// [`ColorChoice`](crate::color::ColorChoice) and `NO_COLOR`.
+///
 /// # Errors
 /// Returns an `io::Error` if writing to stdout fails.
 pub fn write_output(cfg: &OutputConfig, message: &str) -> io::Result<()> {
-    let mut stdout = io::stdout();
+    let mut stdout = backend::stdout();
     let wrapped = wrap_text(message, cfg.max_chars_per_line);
 
     for line in wrapped {
@@ -92,16 +106,14 @@ pub fn write_output(cfg: &OutputConfig, message: &str) -> io::Result<()> {
             stdout.execute(Print(indent.clone()))?;
         }
         if !cfg.prefix.is_empty() {
-            stdout.execute(PrintStyledContent(
-                cfg.prefix.clone().with(cfg.prefix_color.into()),
-            ))?;
+            stdout.execute(PrintStyledContent(cfg.prefix_color.apply(&cfg.prefix)))?;
         }
         if let Some(ref level) = cfg.log_level {
             stdout.execute(PrintStyledContent(
-                format!("[{}] ", level).with(cfg.prefix_color.into()),
+                cfg.prefix_color.apply(format!("[{}] ", level)),
             ))?;
         }
-        stdout.execute(PrintStyledContent(line.with(cfg.text_color.into())))?;
+        stdout.execute(PrintStyledContent(cfg.text_color.apply(line)))?;
         stdout.execute(Print("\n"))?;
     }
     stdout.flush()
@@ -1,11 +1,16 @@
-//! Interactive prompts for confirmation, pattern-validated, and numeric input.
+//! Interactive prompts for confirmation, pattern-validated, numeric, typed and path input.
+//!
+//! Every prompt can be answered non-interactively through [`crate::io::answers`],
+//! keyed by its message.
 //!
 //! # Examples
 //!
-//! ```rust
-//! use prettui::io::prompt::{confirm, read_matching, read_number, ConfirmConfig, RegexConfig, NumberConfig};
+//! ```rust,no_run
+//! use prettui::io::prompt::{confirm, read_matching, read_number, read_parsed, read_path, ConfirmConfig, RegexConfig, NumberConfig, ParsedConfig, PathConfig, PathKind};
 //! use prettui::io::input::InputConfig;
 //! use regex::Regex;
+//! use std::net::IpAddr;
+//! use std::sync::Arc;
 //!
 //! fn main() -> std::io::Result<()> {
 //!     let input_cfg = InputConfig::default();
@@ -25,22 +30,44 @@
 //!     let email = read_matching("Enter your email", &email_pattern, &regex_cfg, &input_cfg)?;
 //!     println!("Email: {}", email);
 //!
-//!     // Number input with range 1-10
-//!     let num_cfg = NumberConfig { min: Some(1), max: Some(10), error_message: Some("Must be 1-10".into()), ..Default::default() };
+//!     // Even number input with range 1-10, 4 on empty Enter
+//!     let even = |n: &i64| if n % 2 == 0 { Ok(()) } else { Err("Must be even".to_string()) };
+//!     let num_cfg = NumberConfig { min: Some(1), max: Some(10), validator: Some(Arc::new(even)), default: Some(4), ..Default::default() };
 //!     let count = read_number("Select count", &num_cfg, &input_cfg)?;
 //!     println!("Count: {}", count);
 //!
+//!     // Any `FromStr` type: a ratio between 0.5 and 2.0, and an IP address
+//!     let ratio: f64 = read_parsed("Scale", &ParsedConfig::new().min(0.5).max(2.0), &input_cfg)?;
+//!     let ip_cfg = ParsedConfig::new().validator(|ip: &IpAddr| {
+//!         if ip.is_loopback() { Err("Loopback addresses are not allowed".into()) } else { Ok(()) }
+//!     });
+//!     let ip = read_parsed("Server address", &ip_cfg, &input_cfg)?;
+//!     println!("Scale: {}, server: {}", ratio, ip);
+//!
+//!     // Path to an existing directory, with Tab completion and `~` expansion
+//!     let path_cfg = PathConfig { kind: PathKind::Dir, ..Default::default() };
+//!     let dir = read_path("Output directory", &path_cfg, &input_cfg)?;
+//!     println!("Directory: {}", dir.display());
+//!
 //!     Ok(())
 //! }
 //!```
 
-use crate::color::Stylize;
-use crate::io::input::InputConfig;
+use crate::backend;
+use crate::io::answers;
+use crate::io::completion::{expand_tilde, is_writable, PathCompleter};
+use crate::io::input::{read_reply, InputConfig};
+use crate::io::validate::{self, InRange, Validator};
 use regex::Regex;
+use std::fmt;
 use std::io::{self, Write};
+use std::ops::Bound;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
 
 /// Configuration for confirmation prompts (yes/no).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct ConfirmConfig {
     /// Default choice when user presses Enter without input.
     /// - Some(true): default to yes ([Y/n])
@@ -51,15 +78,6 @@ pub struct ConfirmConfig {
     pub case_sensitive: bool,
 }
 
-impl Default for ConfirmConfig {
-    fn default() -> Self {
-        Self {
-            default: None,
-            case_sensitive: false,
-        }
-    }
-}
-
 /// Configuration for regex-validated input.
 #[derive(Debug, Clone)]
 pub struct RegexConfig {
@@ -69,6 +87,11 @@ pub struct RegexConfig {
     pub max_attempts: Option<usize>,
     /// If true, show the regex pattern in the prompt.
     pub show_pattern: bool,
+    /// Extra check run on input matching the pattern.
+    pub validator: Option<Arc<dyn Validator<str>>>,
+    /// Default input when user presses Enter without input; shown dimmed and
+    /// validated like typed input. Overrides `InputConfig::default`.
+    pub default: Option<String>,
 }
 
 impl Default for RegexConfig {
@@ -77,6 +100,8 @@ impl Default for RegexConfig {
             error_message: None,
             max_attempts: Some(3),
             show_pattern: false,
+            validator: None,
+            default: None,
         }
     }
 }
@@ -92,6 +117,11 @@ pub struct NumberConfig {
     pub error_message: Option<String>,
     /// Maximum retry attempts; None = unlimited.
     pub max_attempts: Option<usize>,
+    /// Extra check run on numbers within the bounds.
+    pub validator: Option<Arc<dyn Validator<i64>>>,
+    /// Default number when user presses Enter without input; shown dimmed and
+    /// validated like typed input. Overrides `InputConfig::default`.
+    pub default: Option<i64>,
 }
 
 impl Default for NumberConfig {
@@ -101,14 +131,161 @@ impl Default for NumberConfig {
             max: None,
             error_message: None,
             max_attempts: Some(3),
+            validator: None,
+            default: None,
         }
     }
 }
 
+/// Kind of file system entry a path prompt accepts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PathKind {
+    /// Any path, existing or not (unless `must_exist` is set).
+    #[default]
+    Any,
+    /// An existing regular file.
+    File,
+    /// An existing directory.
+    Dir,
+}
+
+/// Configuration for path prompts.
+#[derive(Debug, Clone)]
+pub struct PathConfig {
+    /// If true, the path must exist.
+    pub must_exist: bool,
+    /// Required kind of entry; `File` and `Dir` imply `must_exist`.
+    pub kind: PathKind,
+    /// If true, the path must be writable (or creatable, if it does not exist)
+    /// by this process, checked by opening the file or creating and removing
+    /// a file in the directory.
+    pub writable: bool,
+    /// If true, a leading `~` is expanded to the home directory.
+    pub expand_tilde: bool,
+    /// Optional error message shown when the path is rejected.
+    pub error_message: Option<String>,
+    /// Maximum retry attempts; None = unlimited.
+    pub max_attempts: Option<usize>,
+    /// Extra check run on paths passing the checks above.
+    pub validator: Option<Arc<dyn Validator<Path>>>,
+}
+
+impl Default for PathConfig {
+    fn default() -> Self {
+        Self {
+            must_exist: false,
+            kind: PathKind::Any,
+            writable: false,
+            expand_tilde: true,
+            error_message: None,
+            max_attempts: Some(3),
+            validator: None,
+        }
+    }
+}
+
+/// Configuration for prompts reading any `FromStr` type with [`read_parsed`].
+///
+/// Bounds and validators are added with the builder methods; `min` and `max`
+/// are available for types that are `PartialOrd` and `Display`.
+pub struct ParsedConfig<T> {
+    /// Optional error message shown instead of the parse or validation error.
+    pub error_message: Option<String>,
+    /// Maximum retry attempts; None = unlimited.
+    pub max_attempts: Option<usize>,
+    /// Displayed lower and upper bounds, for the prompt hint.
+    bounds: (Option<String>, Option<String>),
+    validators: Vec<Arc<dyn Validator<T>>>,
+}
+
+impl<T> Default for ParsedConfig<T> {
+    fn default() -> Self {
+        Self {
+            error_message: None,
+            max_attempts: Some(3),
+            bounds: (None, None),
+            validators: Vec::new(),
+        }
+    }
+}
+
+impl<T> Clone for ParsedConfig<T> {
+    fn clone(&self) -> Self {
+        Self {
+            error_message: self.error_message.clone(),
+            max_attempts: self.max_attempts,
+            bounds: self.bounds.clone(),
+            validators: self.validators.clone(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for ParsedConfig<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ParsedConfig")
+            .field("error_message", &self.error_message)
+            .field("max_attempts", &self.max_attempts)
+            .field("min", &self.bounds.0)
+            .field("max", &self.bounds.1)
+            .field("validators", &self.validators.len())
+            .finish()
+    }
+}
+
+impl<T> ParsedConfig<T> {
+    /// Create a configuration with the defaults (3 attempts, no validators).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a validator; its error message is shown when it rejects a value.
+    pub fn validator(mut self, validator: impl Validator<T> + 'static) -> Self {
+        self.validators.push(Arc::new(validator));
+        self
+    }
+
+    /// Set the error message shown instead of the parse or validation error.
+    pub fn error_message(mut self, message: impl Into<String>) -> Self {
+        self.error_message = Some(message.into());
+        self
+    }
+
+    /// Set the maximum retry attempts; None = unlimited.
+    pub fn max_attempts(mut self, val: Option<usize>) -> Self {
+        self.max_attempts = val;
+        self
+    }
+
+    /// Range hint shown in the prompt, like `read_number`'s.
+    fn hint(&self) -> String {
+        match &self.bounds {
+            (Some(min), Some(max)) => format!(" ({}-{})", min, max),
+            (Some(min), None) => format!(" (>= {})", min),
+            (None, Some(max)) => format!(" (<= {})", max),
+            (None, None) => String::new(),
+        }
+    }
+}
+
+impl<T: PartialOrd + fmt::Display + Send + Sync + 'static> ParsedConfig<T> {
+    /// Require values of at least `min`.
+    pub fn min(mut self, min: T) -> Self {
+        self.bounds.0 = Some(min.to_string());
+        self.validator(InRange::at_least(min))
+    }
+
+    /// Require values of at most `max`.
+    pub fn max(mut self, max: T) -> Self {
+        self.bounds.1 = Some(max.to_string());
+        self.validator(InRange::at_most(max))
+    }
+}
+
 /// Ask user a yes/no question and return true for yes, false for no.
 ///
 /// # Errors
-/// Returns io::Error if stdin/stdout fail.
+/// Returns io::Error if stdin/stdout fail, or if a preset answer is invalid
+/// or missing without a terminal.
 pub fn confirm(message: &str, cfg: &ConfirmConfig, input_cfg: &InputConfig) -> io::Result<bool> {
     // Build indicator suffix
     let indicator = match cfg.default {
@@ -117,17 +294,8 @@ pub fn confirm(message: &str, cfg: &ConfirmConfig, input_cfg: &InputConfig) -> i
         None => "[y/n]",
     };
 
-    loop {
-        // Print prompt
-        let prompt = format!("{} {} {}: ", input_cfg.prefix.trim(), message, indicator);
-        print_styled(&prompt, input_cfg);
-        io::stdout().flush()?;
-
-        // Read
-        let mut line = String::new();
-        io::stdin().read_line(&mut line)?;
-        let input = line.trim();
-
+    let prompt = format!("{} {} {}: ", input_cfg.prefix.trim(), message, indicator);
+    let parse = |input: &str| {
         // Default
         if input.is_empty() {
             if let Some(def) = cfg.default {
@@ -144,17 +312,41 @@ pub fn confirm(message: &str, cfg: &ConfirmConfig, input_cfg: &InputConfig) -> i
 
         // Match
         match val.as_str() {
-            "y" | "yes" => return Ok(true),
-            "n" | "no" => return Ok(false),
-            _ => {
-                print_error("Please enter 'y' or 'n'", input_cfg);
-            }
+            "y" | "yes" => Ok(true),
+            "n" | "no" => Ok(false),
+            _ => Err(String::from("Please enter 'y' or 'n'")),
+        }
+    };
+
+    let default = cfg.default.map(|def| if def { "yes" } else { "no" });
+    if let Some(answer) = answers::preset(Some(message), default)? {
+        // Booleans from answers files and environment variables
+        let answer = match answer.trim() {
+            "true" => String::from("yes"),
+            "false" => String::from("no"),
+            _ => answer,
+        };
+        return answered(message, &prompt, &answer, None, input_cfg, parse);
+    }
+
+    loop {
+        // Print prompt
+        print_styled(&prompt, input_cfg)?;
+        backend::stdout().flush()?;
+
+        // Read
+        let line = read_reply(input_cfg, None)?;
+        match parse(line.trim()) {
+            Ok(val) => return Ok(val),
+            Err(problem) => print_error(&problem, input_cfg)?,
         }
     }
 }
 
 /// Read a line matching a regex pattern.
 ///
+/// The line must also pass `cfg.validator`, if set.
+///
 /// # Errors
 /// Returns Err after max_attempts or IO errors.
 pub fn read_matching(
@@ -163,92 +355,240 @@ pub fn read_matching(
     cfg: &RegexConfig,
     input_cfg: &InputConfig,
 ) -> io::Result<String> {
-    let mut attempts = 0;
-    loop {
-        // Build prompt
-        let hint = if cfg.show_pattern {
-            format!(" (pattern: {})", pattern.as_str())
-        } else {
-            String::new()
-        };
-        let prompt = format!("{} {}{}: ", input_cfg.prefix.trim(), message, hint);
-        print_styled(&prompt, input_cfg);
-        io::stdout().flush()?;
+    let hint = if cfg.show_pattern {
+        format!(" (pattern: {})", pattern.as_str())
+    } else {
+        String::new()
+    };
+    let prompt = format!("{} {}{}: ", input_cfg.prefix.trim(), message, hint);
+    let input_cfg = &with_default(input_cfg, cfg.default.clone());
+    let error_message = cfg.error_message.as_deref();
+    ask(
+        message,
+        &prompt,
+        error_message,
+        cfg.max_attempts,
+        input_cfg,
+        |input| {
+            validate::matches(pattern.clone()).validate(input)?;
+            validate_with(&cfg.validator, input)?;
+            Ok(input.to_string())
+        },
+    )
+}
 
-        let mut line = String::new();
-        io::stdin().read_line(&mut line)?;
-        let input = line.trim().to_string();
+/// Read an integer within optional bounds.
+///
+/// The number must also pass `cfg.validator`, if set.
+///
+/// # Errors
+/// Returns Err after max_attempts or IO errors.
+pub fn read_number(message: &str, cfg: &NumberConfig, input_cfg: &InputConfig) -> io::Result<i64> {
+    // Build range hint
+    let range = match (cfg.min, cfg.max) {
+        (Some(min), Some(max)) => format!(" ({}-{})", min, max),
+        (Some(min), None) => format!(" (>= {})", min),
+        (None, Some(max)) => format!(" (<= {})", max),
+        (None, None) => "".to_string(),
+    };
+    let prompt = format!("{} {}{}: ", input_cfg.prefix.trim(), message, range);
+    let input_cfg = &with_default(input_cfg, cfg.default.map(|num| num.to_string()));
+    let bounds = validate::range((
+        cfg.min.map_or(Bound::Unbounded, Bound::Included),
+        cfg.max.map_or(Bound::Unbounded, Bound::Included),
+    ));
+    let error_message = cfg.error_message.as_deref();
+    ask(
+        message,
+        &prompt,
+        error_message,
+        cfg.max_attempts,
+        input_cfg,
+        |input| {
+            let num = input
+                .parse::<i64>()
+                .map_err(|_| String::from("Invalid number"))?;
+            bounds.validate(&num)?;
+            validate_with(&cfg.validator, &num)?;
+            Ok(num)
+        },
+    )
+}
 
-        if pattern.is_match(&input) {
-            return Ok(input);
-        }
+/// Read a value of any type implementing `FromStr`.
+///
+/// Input that fails to parse is reported with the `T::Err` message; parsed
+/// values must then pass the bounds and validators of `cfg`.
+///
+/// # Errors
+/// Returns Err after max_attempts or IO errors.
+pub fn read_parsed<T>(
+    message: &str,
+    cfg: &ParsedConfig<T>,
+    input_cfg: &InputConfig,
+) -> io::Result<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    let prompt = format!("{} {}{}: ", input_cfg.prefix.trim(), message, cfg.hint());
+    let error_message = cfg.error_message.as_deref();
+    ask(
+        message,
+        &prompt,
+        error_message,
+        cfg.max_attempts,
+        input_cfg,
+        |input| {
+            let value = input
+                .parse::<T>()
+                .map_err(|err| format!("Invalid value: {}", err))?;
+            cfg.validators
+                .iter()
+                .try_for_each(|validator| validator.validate(&value))?;
+            Ok(value)
+        },
+    )
+}
 
-        attempts += 1;
-        if let Some(max) = cfg.max_attempts {
-            if attempts >= max {
-                return Err(io::Error::new(
-                    io::ErrorKind::InvalidInput,
-                    cfg.error_message
-                        .clone()
-                        .unwrap_or_else(|| "Invalid input".into()),
-                ));
-            }
-        }
-        print_error(
-            cfg.error_message
-                .as_deref()
-                .unwrap_or("Input does not match pattern"),
-            input_cfg,
-        );
+/// Read a file system path, completing directory entries on Tab.
+///
+/// The path is checked against `cfg`; the returned path has `~` expanded if
+/// `cfg.expand_tilde` is set. `input_cfg.completer` is used instead of the
+/// built-in path completion if it is set.
+///
+/// # Errors
+/// Returns Err after max_attempts or IO errors.
+pub fn read_path(message: &str, cfg: &PathConfig, input_cfg: &InputConfig) -> io::Result<PathBuf> {
+    let mut input_cfg = input_cfg.clone();
+    if input_cfg.completer.is_none() {
+        let completer = PathCompleter::new()
+            .whole_line(true)
+            .dirs_only(cfg.kind == PathKind::Dir);
+        input_cfg.completer = Some(Arc::new(completer));
     }
+
+    let prompt = format!("{} {}: ", input_cfg.prefix.trim(), message);
+    let error_message = cfg.error_message.as_deref();
+    ask(
+        message,
+        &prompt,
+        error_message,
+        cfg.max_attempts,
+        &input_cfg,
+        |input| {
+            let path = if cfg.expand_tilde {
+                expand_tilde(input)
+            } else {
+                PathBuf::from(input)
+            };
+
+            let problem = if input.is_empty() {
+                Some("Path is empty")
+            } else if (cfg.must_exist || cfg.kind != PathKind::Any) && !path.exists() {
+                Some("Path does not exist")
+            } else if cfg.kind == PathKind::File && !path.is_file() {
+                Some("Path is not a file")
+            } else if cfg.kind == PathKind::Dir && !path.is_dir() {
+                Some("Path is not a directory")
+            } else if cfg.writable && !is_writable(&path) {
+                Some("Path is not writable")
+            } else {
+                None
+            };
+            if let Some(problem) = problem {
+                return Err(problem.into());
+            }
+            validate_with(&cfg.validator, path.as_path())?;
+            Ok(path)
+        },
+    )
 }
 
-/// Read an integer within optional bounds.
+/// Show `prompt` and read replies until `parse` accepts one, reporting each
+/// rejection (or `error_message`, if set) as an error. With live validation
+/// the same message is shown while typing and rejected replies are not submitted.
+/// A preset answer for `message` (see [`answers`]) is used without prompting.
 ///
 /// # Errors
-/// Returns Err after max_attempts or IO errors.
-pub fn read_number(message: &str, cfg: &NumberConfig, input_cfg: &InputConfig) -> io::Result<i64> {
+/// Returns an `InvalidInput` error with the last message once `max_attempts`
+/// replies were rejected or if a preset answer is rejected, a `NotFound` error
+/// if an answer is required but missing, or any IO error.
+fn ask<T>(
+    message: &str,
+    prompt: &str,
+    error_message: Option<&str>,
+    max_attempts: Option<usize>,
+    input_cfg: &InputConfig,
+    parse: impl Fn(&str) -> Result<T, String>,
+) -> io::Result<T> {
+    let check = |line: &str| {
+        parse(line.trim())
+            .map(drop)
+            .map_err(|problem| error_message.map_or(problem, String::from))
+    };
+    if let Some(answer) = answers::preset(Some(message), input_cfg.default.as_deref())? {
+        return answered(message, prompt, &answer, error_message, input_cfg, parse);
+    }
+
     let mut attempts = 0;
     loop {
-        // Build range hint
-        let range = match (cfg.min, cfg.max) {
-            (Some(min), Some(max)) => format!(" ({}-{})", min, max),
-            (Some(min), None) => format!(" (>= {})", min),
-            (None, Some(max)) => format!(" (<= {})", max),
-            (None, None) => "".to_string(),
+        print_styled(prompt, input_cfg)?;
+        backend::stdout().flush()?;
+
+        let line = read_reply(input_cfg, Some(&check))?;
+        let problem = match parse(line.trim()) {
+            Ok(value) => return Ok(value),
+            Err(problem) => problem,
         };
-        let prompt = format!("{} {}{}: ", input_cfg.prefix.trim(), message, range);
-        print_styled(&prompt, input_cfg);
-        io::stdout().flush()?;
-
-        let mut line = String::new();
-        io::stdin().read_line(&mut line)?;
-        let text = line.trim();
-        match text.parse::<i64>() {
-            Ok(num) if cfg.min.map_or(true, |m| num >= m) && cfg.max.map_or(true, |m| num <= m) => {
-                return Ok(num);
-            }
-            _ => {
-                attempts += 1;
-                if let Some(max) = cfg.max_attempts {
-                    if attempts >= max {
-                        return Err(io::Error::new(
-                            io::ErrorKind::InvalidInput,
-                            cfg.error_message
-                                .clone()
-                                .unwrap_or_else(|| "Invalid number input".into()),
-                        ));
-                    }
-                }
-                let msg = cfg.error_message.as_deref().unwrap_or("Invalid number");
-                print_error(msg, input_cfg);
-            }
+
+        attempts += 1;
+        let problem = error_message.map_or(problem, String::from);
+        if max_attempts.is_some_and(|max| attempts >= max) {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, problem));
         }
+        print_error(&problem, input_cfg)?;
     }
 }
 
+/// Print `prompt` followed by the preset `answer` and parse the answer,
+/// failing instead of retrying if it is rejected.
+fn answered<T>(
+    message: &str,
+    prompt: &str,
+    answer: &str,
+    error_message: Option<&str>,
+    input_cfg: &InputConfig,
+    parse: impl Fn(&str) -> Result<T, String>,
+) -> io::Result<T> {
+    print_styled(prompt, input_cfg)?;
+    writeln!(backend::stdout(), "{}", answer)?;
+    parse(answer.trim()).map_err(|problem| {
+        answers::invalid_answer(message, &error_message.map_or(problem, String::from))
+    })
+}
+
+/// `input_cfg` with its default replaced by the prompt's own `default`, if set.
+fn with_default(input_cfg: &InputConfig, default: Option<String>) -> InputConfig {
+    let mut input_cfg = input_cfg.clone();
+    if default.is_some() {
+        input_cfg.default = default;
+    }
+    input_cfg
+}
+
+/// Run the optional `validator` on `value`.
+fn validate_with<T: ?Sized>(
+    validator: &Option<Arc<dyn Validator<T>>>,
+    value: &T,
+) -> Result<(), String> {
+    validator
+        .as_ref()
+        .map_or(Ok(()), |validator| validator.validate(value))
+}
+
 /// Print a prompt or text using InputConfig styling.
-fn print_styled(text: &str, cfg: &InputConfig) {
+fn print_styled(text: &str, cfg: &InputConfig) -> io::Result<()> {
     let mut styled = String::new();
     // indent
     if cfg.indent_level > 0 {
@@ -256,15 +596,16 @@ fn print_styled(text: &str, cfg: &InputConfig) {
     }
     // prefix
     styled.push_str(&cfg.prefix);
-    // finally the prompt text in prompt_color
+    // finally the prompt text in the prompt_color style
     styled.push_str(text);
-    print!("{}", styled.with(cfg.prompt_color.into()));
+    write!(backend::stdout(), "{}", cfg.prompt_color.apply(styled))
 }
 
-/// Print an error message to stderr using input_text_color.
-fn print_error(message: &str, cfg: &InputConfig) {
-    eprintln!(
+/// Print an error message to stderr in the invalid_color style.
+pub(crate) fn print_error(message: &str, cfg: &InputConfig) -> io::Result<()> {
+    writeln!(
+        backend::stderr(),
         "{}",
-        format!("Error: {}", message).with(cfg.input_text_color.into())
-    );
+        cfg.invalid_color.apply(format!("Error: {}", message))
+    )
 }
@@ -0,0 +1,339 @@
+//! Composable validation for prompts and `read_input`.
+//!
+//! A [`Validator`] accepts a value or rejects it with a message shown to the
+//! user. Validators combine with [`and`](Validator::and), [`or`](Validator::or)
+//! and [`not`](Validator::not), and [`message`](Validator::message) replaces
+//! the message of any validator. Closures `Fn(&T) -> Result<(), String>` are
+//! validators too.
+//!
+//! Built-ins: [`non_empty`], [`length`], [`matches`], [`range`] and [`one_of`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use prettui::io::validate::{length, matches, non_empty, one_of, range, Validator};
+//! use regex::Regex;
+//!
+//! let username = non_empty()
+//!     .and(length(3..=16))
+//!     .and(matches(Regex::new("^[a-z0-9_]+$").unwrap()).message("Use lowercase letters, digits and _"));
+//! assert!(username.validate("ferris_42").is_ok());
+//! assert_eq!(username.validate("ab"), Err("Must be at least 3 characters".into()));
+//! assert_eq!(username.validate("Ferris"), Err("Use lowercase letters, digits and _".into()));
+//!
+//! let port = range(1024..=65535).or(one_of([80, 443]));
+//! assert!(port.validate(&443).is_ok());
+//! assert!(port.validate(&8080).is_ok());
+//! assert!(port.validate(&22).is_err());
+//!
+//! let no_spaces = matches(Regex::new(r"\s").unwrap()).not("Must not contain spaces");
+//! assert_eq!(no_spaces.validate("my name"), Err("Must not contain spaces".into()));
+//!
+//! let shell = one_of(["bash", "zsh", "fish"]).ignore_case(true);
+//! assert!(shell.validate("Zsh").is_ok());
+//! assert_eq!(shell.validate("csh"), Err("Must be one of: bash, zsh, fish".into()));
+//! ```
+
+use regex::Regex;
+use std::fmt;
+use std::ops::{Bound, RangeBounds};
+use std::sync::Arc;
+
+/// Checks a value, returning an error message if it is rejected.
+pub trait Validator<T: ?Sized>: Send + Sync {
+    /// Accept `value`, or reject it with a message for the user.
+    fn validate(&self, value: &T) -> Result<(), String>;
+
+    /// Require both validators to accept; the first rejection is reported.
+    fn and<V: Validator<T>>(self, other: V) -> And<Self, V>
+    where
+        Self: Sized,
+    {
+        And(self, other)
+    }
+
+    /// Require either validator to accept; if both reject, both messages are reported.
+    fn or<V: Validator<T>>(self, other: V) -> Or<Self, V>
+    where
+        Self: Sized,
+    {
+        Or(self, other)
+    }
+
+    /// Accept exactly the values this validator rejects, reporting `message` otherwise.
+    fn not(self, message: impl Into<String>) -> Not<Self>
+    where
+        Self: Sized,
+    {
+        Not(self, message.into())
+    }
+
+    /// Report `message` instead of this validator's own message.
+    fn message(self, message: impl Into<String>) -> WithMessage<Self>
+    where
+        Self: Sized,
+    {
+        WithMessage(self, message.into())
+    }
+}
+
+impl<T: ?Sized, F> Validator<T> for F
+where
+    F: Fn(&T) -> Result<(), String> + Send + Sync,
+{
+    fn validate(&self, value: &T) -> Result<(), String> {
+        self(value)
+    }
+}
+
+impl<T: ?Sized, V: Validator<T> + ?Sized> Validator<T> for Arc<V> {
+    fn validate(&self, value: &T) -> Result<(), String> {
+        (**self).validate(value)
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for dyn Validator<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Validator")
+    }
+}
+
+/// Validator built by [`Validator::and`].
+#[derive(Debug, Clone)]
+pub struct And<A, B>(A, B);
+
+impl<T: ?Sized, A: Validator<T>, B: Validator<T>> Validator<T> for And<A, B> {
+    fn validate(&self, value: &T) -> Result<(), String> {
+        self.0.validate(value)?;
+        self.1.validate(value)
+    }
+}
+
+/// Validator built by [`Validator::or`].
+#[derive(Debug, Clone)]
+pub struct Or<A, B>(A, B);
+
+impl<T: ?Sized, A: Validator<T>, B: Validator<T>> Validator<T> for Or<A, B> {
+    fn validate(&self, value: &T) -> Result<(), String> {
+        match self.0.validate(value) {
+            Ok(()) => Ok(()),
+            Err(first) => self
+                .1
+                .validate(value)
+                .map_err(|second| format!("{} or {}", first, lowercase_first(&second))),
+        }
+    }
+}
+
+/// Validator built by [`Validator::not`].
+#[derive(Debug, Clone)]
+pub struct Not<V>(V, String);
+
+impl<T: ?Sized, V: Validator<T>> Validator<T> for Not<V> {
+    fn validate(&self, value: &T) -> Result<(), String> {
+        match self.0.validate(value) {
+            Ok(()) => Err(self.1.clone()),
+            Err(_) => Ok(()),
+        }
+    }
+}
+
+/// Validator built by [`Validator::message`].
+#[derive(Debug, Clone)]
+pub struct WithMessage<V>(V, String);
+
+impl<T: ?Sized, V: Validator<T>> Validator<T> for WithMessage<V> {
+    fn validate(&self, value: &T) -> Result<(), String> {
+        self.0.validate(value).map_err(|_| self.1.clone())
+    }
+}
+
+/// Rejects text that is empty or only whitespace.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NonEmpty;
+
+/// Reject empty (or whitespace-only) text.
+pub fn non_empty() -> NonEmpty {
+    NonEmpty
+}
+
+impl Validator<str> for NonEmpty {
+    fn validate(&self, value: &str) -> Result<(), String> {
+        if value.trim().is_empty() {
+            Err(String::from("Input must not be empty"))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Checks the number of characters in text.
+#[derive(Debug, Clone, Copy)]
+pub struct Length {
+    min: usize,
+    max: Option<usize>,
+}
+
+/// Require the number of characters to lie in `range`, e.g. `3..=16` or `..80`.
+pub fn length(range: impl RangeBounds<usize>) -> Length {
+    let min = match range.start_bound() {
+        Bound::Included(&min) => min,
+        Bound::Excluded(&min) => min + 1,
+        Bound::Unbounded => 0,
+    };
+    let max = match range.end_bound() {
+        Bound::Included(&max) => Some(max),
+        Bound::Excluded(&max) => Some(max.saturating_sub(1)),
+        Bound::Unbounded => None,
+    };
+    Length { min, max }
+}
+
+impl Validator<str> for Length {
+    fn validate(&self, value: &str) -> Result<(), String> {
+        let len = value.chars().count();
+        match self.max {
+            _ if len < self.min => Err(format!("Must be at least {} characters", self.min)),
+            Some(max) if len > max => Err(format!("Must be at most {} characters", max)),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Requires text to match a regular expression.
+#[derive(Debug, Clone)]
+pub struct Matches(Regex);
+
+/// Require text to match `pattern`.
+pub fn matches(pattern: Regex) -> Matches {
+    Matches(pattern)
+}
+
+impl Validator<str> for Matches {
+    fn validate(&self, value: &str) -> Result<(), String> {
+        if self.0.is_match(value) {
+            Ok(())
+        } else {
+            Err(String::from("Input does not match pattern"))
+        }
+    }
+}
+
+/// Requires a value to lie within bounds.
+#[derive(Debug, Clone)]
+pub struct InRange<T> {
+    start: Bound<T>,
+    end: Bound<T>,
+}
+
+/// Require values to lie in `range`, e.g. `1..=10`, `0.0..1.0` or `18..`.
+pub fn range<T: Clone>(range: impl RangeBounds<T>) -> InRange<T> {
+    InRange {
+        start: range.start_bound().cloned(),
+        end: range.end_bound().cloned(),
+    }
+}
+
+impl<T> InRange<T> {
+    /// Require values of at least `min`.
+    pub(crate) fn at_least(min: T) -> Self {
+        Self {
+            start: Bound::Included(min),
+            end: Bound::Unbounded,
+        }
+    }
+
+    /// Require values of at most `max`.
+    pub(crate) fn at_most(max: T) -> Self {
+        Self {
+            start: Bound::Unbounded,
+            end: Bound::Included(max),
+        }
+    }
+}
+
+impl<T: PartialOrd + fmt::Display + Send + Sync> Validator<T> for InRange<T> {
+    fn validate(&self, value: &T) -> Result<(), String> {
+        match &self.start {
+            Bound::Included(min) if value < min => return Err(format!("Must be at least {}", min)),
+            Bound::Excluded(min) if value <= min => {
+                return Err(format!("Must be greater than {}", min))
+            }
+            _ => {}
+        }
+        match &self.end {
+            Bound::Included(max) if value > max => Err(format!("Must be at most {}", max)),
+            Bound::Excluded(max) if value >= max => Err(format!("Must be less than {}", max)),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Requires a value to be one of a fixed set of options.
+#[derive(Debug, Clone)]
+pub struct OneOf<T> {
+    options: Vec<T>,
+    ignore_case: bool,
+}
+
+/// Require values to equal one of `options`. Options that are strings
+/// (`&str` or `String`) validate text.
+pub fn one_of<T>(options: impl IntoIterator<Item = T>) -> OneOf<T> {
+    OneOf {
+        options: options.into_iter().collect(),
+        ignore_case: false,
+    }
+}
+
+impl<T> OneOf<T> {
+    /// Compare text options regardless of case.
+    pub fn ignore_case(mut self, val: bool) -> Self {
+        self.ignore_case = val;
+        self
+    }
+
+    fn rejection(&self) -> String
+    where
+        T: fmt::Display,
+    {
+        let options: Vec<String> = self.options.iter().map(ToString::to_string).collect();
+        format!("Must be one of: {}", options.join(", "))
+    }
+}
+
+impl<T: PartialEq + fmt::Display + Send + Sync> Validator<T> for OneOf<T> {
+    fn validate(&self, value: &T) -> Result<(), String> {
+        if self.options.contains(value) {
+            Ok(())
+        } else {
+            Err(self.rejection())
+        }
+    }
+}
+
+impl<S: AsRef<str> + fmt::Display + Send + Sync> Validator<str> for OneOf<S> {
+    fn validate(&self, value: &str) -> Result<(), String> {
+        let found = self.options.iter().any(|option| {
+            let option = option.as_ref();
+            if self.ignore_case {
+                option.to_lowercase() == value.to_lowercase()
+            } else {
+                option == value
+            }
+        });
+        if found {
+            Ok(())
+        } else {
+            Err(self.rejection())
+        }
+    }
+}
+
+/// `text` with its first character in lowercase, for joining messages.
+fn lowercase_first(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
@@ -3,13 +3,31 @@
 //! # Features
 //!
 //! - Configurable list with items per row, rows per page, and cell width
-//! - Arrow and page list navigation
+//! - Multi-select lists with checkboxes and selection limits
+//! - Fuzzy filtering of list items as you type
+//! - Grouped lists with section headers
+//! - Live preview pane for the highlighted list item
+//! - Arrow and page list navigation, with optional mouse support
+//! - Configurable list key bindings with a vim preset
 //! - Real-time multi-digit numeric list input with live feedback
-//! - Customizable colors
+//! - Line editing with history and Tab completion for text input
+//! - Typed prompts for any `FromStr` type with bounds and validators
+//! - Path prompts with completion and existence/kind/writability checks
+//! - Composable validators for text input and prompts
+//! - Live validation feedback while typing
+//! - Default values and placeholder text for text input and prompts
+//! - Non-interactive answers from environment variables, answers files (`serde` feature) or defaults
+//! - Plain numbered lists and escape-free output when not attached to a terminal
+//! - Styles with background colors and text attributes for every color setting
+//! - Themes with semantic styles and light/dark presets, shared by every widget
+//! - User theme files and serializable colors, styles and configs (`serde` feature)
+//! - Truecolor and 256-color support, downgraded to what the terminal can show
+//! - Customizable colors, honoring `NO_COLOR`, `FORCE_COLOR` and a global color choice
+//! - Virtual terminal backend for testing interactive code
 //!
 //! # Example
 //!
-//! ```rust
+//! ```rust,no_run
 //! use prettui::prelude::*;
 //!
 //! fn main() -> anyhow::Result<()> {
@@ -32,7 +50,7 @@
 //!     }
 //!
 //!     let ic = InputConfig {
-//!         input_text_color: Color::Blue,
+//!         input_text_color: Color::Blue.into(),
 //!         ..Default::default()
 //!     };
 //!     let name = read_input(&ic)?;
@@ -53,12 +71,17 @@
 //! }
 //! ```
 
+pub mod backend;
 pub mod color;
 pub mod io;
 pub mod list;
+pub mod style;
+pub mod theme;
 
 pub mod prelude {
     pub use super::color::*;
     pub use super::io::*;
     pub use super::list::*;
+    pub use super::style::*;
+    pub use super::theme::Theme;
 }
@@ -0,0 +1,137 @@
+//! Fuzzy matching used by the list filter.
+//!
+//! A query matches a candidate when all of its characters appear in the
+//! candidate in order. Matches are scored so that consecutive characters,
+//! characters at word boundaries and matches near the start rank higher.
+//! Matching is case-insensitive unless the query contains an uppercase letter.
+//!
+//! # Example
+//!
+//! ```rust
+//! use prettui::list::fuzzy::fuzzy_match;
+//!
+//! let m = fuzzy_match("fb", "foo_bar").unwrap();
+//! assert_eq!(m.positions, vec![0, 4]);
+//! assert!(fuzzy_match("fb", "bar").is_none());
+//!
+//! // Consecutive matches score higher than scattered ones.
+//! let tight = fuzzy_match("bar", "foo_bar").unwrap();
+//! let loose = fuzzy_match("bar", "b_a_r").unwrap();
+//! assert!(tight.score > loose.score);
+//! ```
+
+/// Score awarded for every matched character.
+const SCORE_MATCH: i64 = 16;
+/// Extra score when a matched character directly follows the previous match.
+const BONUS_CONSECUTIVE: i64 = 12;
+/// Extra score when a matched character starts a word.
+const BONUS_BOUNDARY: i64 = 8;
+/// Penalty for every skipped character between two matches.
+const PENALTY_GAP: i64 = 1;
+
+/// A successful fuzzy match of a query against a candidate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    /// Match quality; higher is better.
+    pub score: i64,
+    /// Character (not byte) positions in the candidate that matched the query.
+    pub positions: Vec<usize>,
+}
+
+/// Match `query` against `candidate`, returning `None` if it does not match.
+///
+/// An empty query matches everything with a score of zero.
+pub fn fuzzy_match(query: &str, candidate: &str) -> Option<FuzzyMatch> {
+    let case_sensitive = query.chars().any(char::is_uppercase);
+    let normalize = |c: char| {
+        if case_sensitive {
+            c
+        } else {
+            c.to_lowercase().next().unwrap_or(c)
+        }
+    };
+    let query: Vec<char> = query.chars().map(normalize).collect();
+    let original: Vec<char> = candidate.chars().collect();
+    let text: Vec<char> = original.iter().copied().map(normalize).collect();
+
+    if query.is_empty() {
+        return Some(FuzzyMatch {
+            score: 0,
+            positions: Vec::new(),
+        });
+    }
+    if query.len() > text.len() {
+        return None;
+    }
+
+    let width = text.len();
+    // scores[i * width + j]: best score with query[i] matched at text[j]
+    let mut scores = vec![None::<i64>; query.len() * width];
+    // from[i * width + j]: where query[i - 1] was matched on that best path
+    let mut from = vec![0usize; query.len() * width];
+
+    for (i, &qc) in query.iter().enumerate() {
+        // Best `score + PENALTY_GAP * k` over previous-row cells k < j - 1
+        let mut best_gapped: Option<(i64, usize)> = None;
+        for j in 0..width {
+            if j >= 2 && i > 0 {
+                if let Some(prev) = scores[(i - 1) * width + j - 2] {
+                    let value = prev + PENALTY_GAP * (j as i64 - 2);
+                    if !matches!(best_gapped, Some((best, _)) if best >= value) {
+                        best_gapped = Some((value, j - 2));
+                    }
+                }
+            }
+            if text[j] != qc {
+                continue;
+            }
+            let own = SCORE_MATCH + boundary_bonus(&original, j);
+            let cell = i * width + j;
+            if i == 0 {
+                // Leading gaps cost a little so earlier matches win ties
+                scores[cell] = Some(own - PENALTY_GAP * j as i64);
+                continue;
+            }
+            let adjacent = (j >= 1)
+                .then(|| scores[(i - 1) * width + j - 1])
+                .flatten()
+                .map(|prev| (prev + BONUS_CONSECUTIVE, j - 1));
+            let gapped = best_gapped.map(|(value, k)| (value - PENALTY_GAP * (j as i64 - 1), k));
+            let best = match (adjacent, gapped) {
+                (Some(a), Some(g)) => Some(if a.0 >= g.0 { a } else { g }),
+                (a, g) => a.or(g),
+            };
+            if let Some((prev, k)) = best {
+                scores[cell] = Some(prev + own);
+                from[cell] = k;
+            }
+        }
+    }
+
+    let last = query.len() - 1;
+    let (mut j, score) = (0..width)
+        .filter_map(|j| scores[last * width + j].map(|score| (j, score)))
+        .max_by_key(|&(j, score)| (score, std::cmp::Reverse(j)))?;
+
+    let mut positions = vec![0; query.len()];
+    for i in (0..query.len()).rev() {
+        positions[i] = j;
+        j = from[i * width + j];
+    }
+    Some(FuzzyMatch { score, positions })
+}
+
+/// Bonus for a character that starts a word: the first character, one after a
+/// separator, or an uppercase letter following a lowercase one.
+fn boundary_bonus(text: &[char], idx: usize) -> i64 {
+    if idx == 0 {
+        return BONUS_BOUNDARY;
+    }
+    let prev = text[idx - 1];
+    let cur = text[idx];
+    if !prev.is_alphanumeric() || (prev.is_lowercase() && cur.is_uppercase()) {
+        BONUS_BOUNDARY
+    } else {
+        0
+    }
+}
@@ -0,0 +1,199 @@
+//! Rendering of individual list items.
+//!
+//! Any type implementing [`ListItem`] can be shown by the list chooser. Every
+//! `ToString` type implements it already, rendering as plain text; implement it
+//! yourself (or use [`ListEntry`]) to show per-item colors, icons, a
+//! description of the highlighted item, right-aligned metadata, or to mark
+//! items as disabled or as separators.
+//!
+//! # Example
+//!
+//! ```rust
+//! use prettui::color::Color;
+//! use prettui::list::{ListEntry, ListItem, Span};
+//!
+//! let entry = ListEntry::new("main.rs")
+//!     .with_icon(Span::new("* ").fg(Color::Green))
+//!     .with_description("Binary entry point")
+//!     .with_metadata("2 KB");
+//! assert_eq!(entry.filter_text(), "* main.rs");
+//! assert_eq!(entry.metadata().as_deref(), Some("2 KB"));
+//! ```
+
+use crate::color::Color;
+
+/// A run of text drawn with one color.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    /// Text to draw.
+    pub text: String,
+    /// Foreground color; `None` uses the list's normal or highlight color.
+    pub fg: Option<Color>,
+}
+
+impl Span {
+    /// Create an uncolored span.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            fg: None,
+        }
+    }
+
+    /// Set the foreground color of the span.
+    pub fn fg(mut self, color: Color) -> Self {
+        self.fg = Some(color);
+        self
+    }
+}
+
+impl From<&str> for Span {
+    fn from(text: &str) -> Self {
+        Span::new(text)
+    }
+}
+
+impl From<String> for Span {
+    fn from(text: String) -> Self {
+        Span::new(text)
+    }
+}
+
+/// An item that can be displayed in a list.
+pub trait ListItem {
+    /// Styled spans making up the item's label.
+    fn spans(&self) -> Vec<Span>;
+
+    /// Secondary text shown below the list while the item is highlighted.
+    fn description(&self) -> Option<String> {
+        None
+    }
+
+    /// Short text drawn right-aligned at the end of the item's cell.
+    fn metadata(&self) -> Option<String> {
+        None
+    }
+
+    /// If true, the item is drawn dimmed and cannot be chosen.
+    fn is_disabled(&self) -> bool {
+        false
+    }
+
+    /// If true, the item is an unnumbered separator line that cannot be chosen.
+    /// Its label, if any, is drawn inside the line.
+    fn is_separator(&self) -> bool {
+        false
+    }
+
+    /// Text matched by the list filter; defaults to the label text.
+    fn filter_text(&self) -> String {
+        self.spans().into_iter().map(|span| span.text).collect()
+    }
+}
+
+impl<T: ToString + ?Sized> ListItem for T {
+    fn spans(&self) -> Vec<Span> {
+        vec![Span::new(self.to_string())]
+    }
+}
+
+/// A ready-made [`ListItem`] built from spans, a description and metadata.
+#[derive(Debug, Clone, Default)]
+pub struct ListEntry {
+    /// Label spans, drawn in order.
+    pub spans: Vec<Span>,
+    /// Description shown while the entry is highlighted.
+    pub description: Option<String>,
+    /// Right-aligned metadata.
+    pub metadata: Option<String>,
+    /// If true, the entry cannot be chosen.
+    pub disabled: bool,
+    /// If true, the entry is a separator line.
+    pub separator: bool,
+}
+
+impl ListEntry {
+    /// Create an entry with a plain label.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            spans: vec![Span::new(label)],
+            ..Default::default()
+        }
+    }
+
+    /// Create a separator line, optionally labelled (pass `""` for a plain line).
+    pub fn separator(label: impl Into<String>) -> Self {
+        let label = label.into();
+        Self {
+            spans: if label.is_empty() {
+                Vec::new()
+            } else {
+                vec![Span::new(label)]
+            },
+            separator: true,
+            ..Default::default()
+        }
+    }
+
+    /// Create an entry from styled spans.
+    pub fn from_spans(spans: Vec<Span>) -> Self {
+        Self {
+            spans,
+            ..Default::default()
+        }
+    }
+
+    /// Prepend an icon span to the label.
+    pub fn with_icon(mut self, icon: impl Into<Span>) -> Self {
+        self.spans.insert(0, icon.into());
+        self
+    }
+
+    /// Set the label color of every uncolored span.
+    pub fn with_fg(mut self, color: Color) -> Self {
+        for span in self.spans.iter_mut().filter(|span| span.fg.is_none()) {
+            span.fg = Some(color);
+        }
+        self
+    }
+
+    /// Set the description shown while the entry is highlighted.
+    pub fn with_description(mut self, text: impl Into<String>) -> Self {
+        self.description = Some(text.into());
+        self
+    }
+
+    /// Set the right-aligned metadata.
+    pub fn with_metadata(mut self, text: impl Into<String>) -> Self {
+        self.metadata = Some(text.into());
+        self
+    }
+
+    /// Mark the entry as disabled.
+    pub fn with_disabled(mut self, val: bool) -> Self {
+        self.disabled = val;
+        self
+    }
+}
+
+impl ListItem for ListEntry {
+    fn spans(&self) -> Vec<Span> {
+        self.spans.clone()
+    }
+
+    fn description(&self) -> Option<String> {
+        self.description.clone()
+    }
+
+    fn metadata(&self) -> Option<String> {
+        self.metadata.clone()
+    }
+
+    fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    fn is_separator(&self) -> bool {
+        self.separator
+    }
+}
@@ -0,0 +1,179 @@
+//! Key bindings for the list chooser.
+//!
+//! A [`KeyMap`] maps key presses to [`ListAction`]s. The default map uses
+//! arrows, PageUp/PageDown, Home/End, Enter, Esc, Space, Ctrl+A and Ctrl+R;
+//! [`KeyMap::vim`] adds `hjkl`, `g`/`G` and Ctrl+D/Ctrl+U on top of it.
+//!
+//! Bound keys take priority over typing: with the vim map and filtering
+//! enabled, `h`, `j`, `k`, `l`, `g` and `G` navigate instead of filtering.
+//!
+//! # Example
+//!
+//! ```rust
+//! use prettui::list::{KeyBinding, KeyCode, KeyMap, ListAction, ListConfig};
+//!
+//! let keymap = KeyMap::vim().bind(KeyBinding::plain(KeyCode::Char('q')), ListAction::Cancel);
+//! assert_eq!(keymap.get(KeyBinding::plain(KeyCode::Char('j'))), Some(ListAction::Down));
+//! assert_eq!(keymap.get(KeyBinding::ctrl('d')), Some(ListAction::PageDown));
+//!
+//! let config = ListConfig::default().keymap(keymap);
+//! ```
+
+use crossterm::event::KeyEvent;
+use std::collections::HashMap;
+
+pub use crossterm::event::{KeyCode, KeyModifiers};
+
+/// Something the user can do in a list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ListAction {
+    /// Move to the previous item.
+    Left,
+    /// Move to the next item.
+    Right,
+    /// Move to the row above.
+    Up,
+    /// Move to the row below.
+    Down,
+    /// Move one page (or group) back.
+    PageUp,
+    /// Move one page (or group) forward.
+    PageDown,
+    /// Move to the first item.
+    First,
+    /// Move to the last item.
+    Last,
+    /// Choose the highlighted item, or confirm the checked items.
+    Confirm,
+    /// Close the list without choosing.
+    Cancel,
+    /// Toggle the highlighted (or typed) item in a multi-select list.
+    Toggle,
+    /// Check every item in a multi-select list, or clear them if all are checked.
+    SelectAll,
+    /// Invert every checkbox in a multi-select list.
+    Invert,
+}
+
+/// A key press with its modifiers.
+///
+/// Shift is ignored for character keys, since it is already reflected in the
+/// character itself (`G` rather than `g`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyBinding {
+    /// The key.
+    pub code: KeyCode,
+    /// Modifiers held with the key.
+    pub modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    /// Create a binding for `code` with `modifiers`.
+    pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        let modifiers = match code {
+            KeyCode::Char(_) => modifiers - KeyModifiers::SHIFT,
+            _ => modifiers,
+        };
+        Self { code, modifiers }
+    }
+
+    /// Create a binding for `code` without modifiers.
+    pub fn plain(code: KeyCode) -> Self {
+        Self::new(code, KeyModifiers::NONE)
+    }
+
+    /// Create a binding for Ctrl+`c`.
+    pub fn ctrl(c: char) -> Self {
+        Self::new(KeyCode::Char(c), KeyModifiers::CONTROL)
+    }
+}
+
+impl From<KeyEvent> for KeyBinding {
+    fn from(key: KeyEvent) -> Self {
+        Self::new(key.code, key.modifiers)
+    }
+}
+
+/// Mapping from key presses to list actions.
+#[derive(Debug, Clone)]
+pub struct KeyMap {
+    bindings: HashMap<KeyBinding, ListAction>,
+}
+
+impl KeyMap {
+    /// Create a map without any bindings.
+    pub fn empty() -> Self {
+        Self {
+            bindings: HashMap::new(),
+        }
+    }
+
+    /// The default bindings: arrows, PageUp/PageDown, Home/End, Enter, Esc,
+    /// Space to toggle, Ctrl+A to select all and Ctrl+R to invert.
+    pub fn standard() -> Self {
+        use ListAction::*;
+        Self::empty()
+            .bind(KeyBinding::plain(KeyCode::Left), Left)
+            .bind(KeyBinding::plain(KeyCode::Right), Right)
+            .bind(KeyBinding::plain(KeyCode::Up), Up)
+            .bind(KeyBinding::plain(KeyCode::Down), Down)
+            .bind(KeyBinding::plain(KeyCode::PageUp), PageUp)
+            .bind(KeyBinding::plain(KeyCode::PageDown), PageDown)
+            .bind(KeyBinding::plain(KeyCode::Home), First)
+            .bind(KeyBinding::plain(KeyCode::End), Last)
+            .bind(KeyBinding::plain(KeyCode::Enter), Confirm)
+            .bind(KeyBinding::plain(KeyCode::Esc), Cancel)
+            .bind(KeyBinding::plain(KeyCode::Char(' ')), Toggle)
+            .bind(KeyBinding::ctrl('a'), SelectAll)
+            .bind(KeyBinding::ctrl('r'), Invert)
+    }
+
+    /// The standard bindings plus vim-style `h`/`j`/`k`/`l`, `g`/`G` for
+    /// first/last and Ctrl+D/Ctrl+U (or Ctrl+F/Ctrl+B) for paging.
+    pub fn vim() -> Self {
+        use ListAction::*;
+        Self::standard()
+            .bind(KeyBinding::plain(KeyCode::Char('h')), Left)
+            .bind(KeyBinding::plain(KeyCode::Char('l')), Right)
+            .bind(KeyBinding::plain(KeyCode::Char('k')), Up)
+            .bind(KeyBinding::plain(KeyCode::Char('j')), Down)
+            .bind(KeyBinding::plain(KeyCode::Char('g')), First)
+            .bind(KeyBinding::plain(KeyCode::Char('G')), Last)
+            .bind(KeyBinding::ctrl('d'), PageDown)
+            .bind(KeyBinding::ctrl('u'), PageUp)
+            .bind(KeyBinding::ctrl('f'), PageDown)
+            .bind(KeyBinding::ctrl('b'), PageUp)
+    }
+
+    /// Bind `key` to `action`, replacing any previous binding of `key`.
+    pub fn bind(mut self, key: KeyBinding, action: ListAction) -> Self {
+        self.bindings.insert(key, action);
+        self
+    }
+
+    /// Remove the binding of `key`.
+    pub fn unbind(mut self, key: KeyBinding) -> Self {
+        self.bindings.remove(&key);
+        self
+    }
+
+    /// Action bound to `key`, if any.
+    pub fn get(&self, key: impl Into<KeyBinding>) -> Option<ListAction> {
+        self.bindings.get(&key.into()).copied()
+    }
+
+    /// Every key bound to `action`.
+    pub fn keys_for(&self, action: ListAction) -> impl Iterator<Item = KeyBinding> + '_ {
+        self.bindings
+            .iter()
+            .filter(move |(_, &bound)| bound == action)
+            .map(|(&key, _)| key)
+    }
+}
+
+impl Default for KeyMap {
+    /// Same as [`KeyMap::standard`].
+    fn default() -> Self {
+        Self::standard()
+    }
+}
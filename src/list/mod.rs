@@ -0,0 +1,1772 @@
+use anyhow::Result;
+use crossterm::{
+    cursor::MoveTo,
+    event::{
+        DisableMouseCapture, EnableMouseCapture, Event, KeyEvent, KeyEventKind, MouseButton,
+        MouseEventKind,
+    },
+    execute,
+    style::{Print, PrintStyledContent, ResetColor},
+    terminal::{Clear, ClearType},
+};
+use std::fmt;
+use std::io::Write;
+use std::ops::Range;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::backend;
+use crate::io::answers;
+use crate::io::input::wrap_text;
+use crate::style::Style;
+use crate::theme;
+use fuzzy::fuzzy_match;
+
+pub mod fuzzy;
+pub mod item;
+pub mod keymap;
+
+pub use item::{ListEntry, ListItem, Span};
+pub use keymap::{KeyBinding, KeyCode, KeyMap, KeyModifiers, ListAction};
+
+/// Callback producing the preview text for the item at the given index.
+///
+/// Wraps the user's closure so that [`ListConfig`] stays `Clone` and `Debug`.
+#[derive(Clone)]
+pub struct Preview(Arc<dyn Fn(usize) -> String + Send + Sync>);
+
+impl Preview {
+    /// Wrap a preview callback.
+    pub fn new(f: impl Fn(usize) -> String + Send + Sync + 'static) -> Self {
+        Self(Arc::new(f))
+    }
+
+    /// Produce the preview text for the item at `index`.
+    pub fn render(&self, index: usize) -> String {
+        (self.0)(index)
+    }
+}
+
+impl fmt::Debug for Preview {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Preview(..)")
+    }
+}
+
+/// Where the preview pane is drawn relative to the list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum PreviewPosition {
+    /// To the right of the item grid, as tall as a page.
+    Right,
+    /// Below the list, `preview_height` lines tall.
+    Bottom,
+}
+
+/// Configuration for layout and visual behavior of the list selection UI.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct ListConfig {
+    /// Number of items displayed per row.
+    pub items_per_row: usize,
+    /// Number of rows displayed per page.
+    pub rows_per_page: usize,
+    /// Width of each cell in characters.
+    pub cell_width: u16,
+    /// Style for non-highlighted items.
+    pub normal_fg: Style,
+    /// Style for the highlighted (selected) item.
+    pub highlight_fg: Style,
+    /// Style for disabled items and separators.
+    pub disabled_fg: Style,
+    /// Style for group headers.
+    pub header_fg: Style,
+    /// Style for messages such as refused selections.
+    pub message_fg: Style,
+    /// If true, PageUp/PageDown jump between groups in grouped lists.
+    pub page_by_group: bool,
+    /// Indices checked when a multi-select list is opened.
+    pub preselected: Vec<usize>,
+    /// Minimum number of checked items required to confirm a multi-select list.
+    pub min_selected: Option<usize>,
+    /// Maximum number of items that can be checked in a multi-select list.
+    pub max_selected: Option<usize>,
+    /// Marker drawn in front of checked items in a multi-select list.
+    pub checked_marker: String,
+    /// Marker drawn in front of unchecked items in a multi-select list.
+    pub unchecked_marker: String,
+    /// If true, typed text fuzzy-filters the items.
+    pub filterable: bool,
+    /// Style for characters matched by the filter.
+    pub match_fg: Style,
+    /// Callback whose output for the highlighted item is shown in a preview
+    /// pane; see [`ListConfig::preview`] for its index.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub preview: Option<Preview>,
+    /// Where the preview pane is drawn.
+    pub preview_position: PreviewPosition,
+    /// Width of a right-hand preview pane in characters.
+    pub preview_width: u16,
+    /// Height of a bottom preview pane in lines.
+    pub preview_height: u16,
+    /// If true, `items_per_row` and `rows_per_page` are derived from the
+    /// terminal size (and re-derived when it is resized).
+    pub auto_layout: bool,
+    /// If true, the mouse is captured: clicking an item highlights it
+    /// (and toggles it in multi-select lists), double-clicking confirms
+    /// (with the item checked), and the scroll wheel moves between pages.
+    pub mouse: bool,
+    /// Key bindings for navigation, confirming, cancelling and toggling.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub keymap: KeyMap,
+    /// Key of the list's preset answer (see [`crate::io::answers`]).
+    pub answer_key: Option<String>,
+}
+
+impl Default for ListConfig {
+    /// Returns a default configuration with:
+    /// - 3 items per row
+    /// - 5 rows per page
+    /// - 20-character-wide cells
+    /// - styles from the installed [`Theme`](crate::theme::Theme): default
+    ///   text for normal items, `highlight` for the selected item, `muted`
+    ///   for disabled items and separators, `prefix` for group headers and
+    ///   `warning` for messages
+    /// - PageUp/PageDown jumping between groups
+    /// - no preselected items and no selection limits
+    /// - Ctrl+A to select all and Ctrl+R to invert the selection
+    /// - `[x]` / `[ ]` checkbox markers
+    /// - filtering disabled, matches in the underlined `highlight` style
+    /// - no preview; when one is set, a 40-column pane on the right
+    ///   (or 6 lines at the bottom)
+    /// - fixed layout (no auto-derived rows and columns)
+    /// - no mouse capture
+    /// - [`KeyMap::standard`] key bindings
+    /// - no answer key
+    fn default() -> Self {
+        let theme = theme::current();
+        Self {
+            items_per_row: 3,
+            rows_per_page: 5,
+            cell_width: 20,
+            normal_fg: Style::new(),
+            highlight_fg: theme.highlight,
+            disabled_fg: theme.muted,
+            header_fg: theme.prefix,
+            message_fg: theme.warning,
+            page_by_group: true,
+            preselected: Vec::new(),
+            min_selected: None,
+            max_selected: None,
+            checked_marker: String::from("[x]"),
+            unchecked_marker: String::from("[ ]"),
+            filterable: false,
+            match_fg: theme.highlight.underline(),
+            preview: None,
+            preview_position: PreviewPosition::Right,
+            preview_width: 40,
+            preview_height: 6,
+            auto_layout: false,
+            mouse: false,
+            keymap: KeyMap::standard(),
+            answer_key: None,
+        }
+    }
+}
+
+impl ListConfig {
+    /// Set number of items per row.
+    pub fn items_per_row(mut self, val: usize) -> Self {
+        self.items_per_row = val;
+        self
+    }
+
+    /// Set number of rows per page.
+    pub fn rows_per_page(mut self, val: usize) -> Self {
+        self.rows_per_page = val;
+        self
+    }
+
+    /// Set cell width for rendering items.
+    pub fn cell_width(mut self, val: u16) -> Self {
+        self.cell_width = val;
+        self
+    }
+
+    /// Set the normal style, or just its foreground color.
+    pub fn normal_fg(mut self, style: impl Into<Style>) -> Self {
+        self.normal_fg = style.into();
+        self
+    }
+
+    /// Set the highlight style, or just its foreground color.
+    pub fn highlight_fg(mut self, style: impl Into<Style>) -> Self {
+        self.highlight_fg = style.into();
+        self
+    }
+
+    /// Set the style for disabled items and separators.
+    pub fn disabled_fg(mut self, style: impl Into<Style>) -> Self {
+        self.disabled_fg = style.into();
+        self
+    }
+
+    /// Set the style for group headers.
+    pub fn header_fg(mut self, style: impl Into<Style>) -> Self {
+        self.header_fg = style.into();
+        self
+    }
+
+    /// Set the style for messages such as refused selections.
+    pub fn message_fg(mut self, style: impl Into<Style>) -> Self {
+        self.message_fg = style.into();
+        self
+    }
+
+    /// Set whether PageUp/PageDown jump between groups in grouped lists.
+    pub fn page_by_group(mut self, val: bool) -> Self {
+        self.page_by_group = val;
+        self
+    }
+
+    /// Set the items checked when a multi-select list is opened.
+    pub fn preselected(mut self, indices: impl IntoIterator<Item = usize>) -> Self {
+        self.preselected = indices.into_iter().collect();
+        self
+    }
+
+    /// Set the minimum number of checked items required to confirm.
+    pub fn min_selected(mut self, val: usize) -> Self {
+        self.min_selected = Some(val);
+        self
+    }
+
+    /// Set the maximum number of items that can be checked.
+    pub fn max_selected(mut self, val: usize) -> Self {
+        self.max_selected = Some(val);
+        self
+    }
+
+    /// Set the checked and unchecked checkbox markers.
+    pub fn checkbox_markers(mut self, checked: &str, unchecked: &str) -> Self {
+        self.checked_marker = checked.to_string();
+        self.unchecked_marker = unchecked.to_string();
+        self
+    }
+
+    /// Enable or disable fuzzy filtering by typed text.
+    pub fn filterable(mut self, val: bool) -> Self {
+        self.filterable = val;
+        self
+    }
+
+    /// Set the style of characters matched by the filter.
+    pub fn match_fg(mut self, style: impl Into<Style>) -> Self {
+        self.match_fg = style.into();
+        self
+    }
+
+    /// Show a preview pane with `f(index)` for the highlighted item,
+    /// where `index` refers to the original items. For
+    /// [`choose_from_groups`], it counts the items of all groups in order, so
+    /// the first item of the second group follows the last of the first.
+    pub fn preview(mut self, f: impl Fn(usize) -> String + Send + Sync + 'static) -> Self {
+        self.preview = Some(Preview::new(f));
+        self
+    }
+
+    /// Set where the preview pane is drawn.
+    pub fn preview_position(mut self, position: PreviewPosition) -> Self {
+        self.preview_position = position;
+        self
+    }
+
+    /// Set the width of a right-hand preview pane.
+    pub fn preview_width(mut self, val: u16) -> Self {
+        self.preview_width = val;
+        self
+    }
+
+    /// Set the height of a bottom preview pane.
+    pub fn preview_height(mut self, val: u16) -> Self {
+        self.preview_height = val;
+        self
+    }
+
+    /// Derive items per row and rows per page from the terminal size.
+    pub fn auto_layout(mut self, val: bool) -> Self {
+        self.auto_layout = val;
+        self
+    }
+
+    /// Enable or disable mouse selection and scrolling.
+    pub fn mouse(mut self, val: bool) -> Self {
+        self.mouse = val;
+        self
+    }
+
+    /// Set the key bindings, e.g. [`KeyMap::vim`].
+    pub fn keymap(mut self, keymap: KeyMap) -> Self {
+        self.keymap = keymap;
+        self
+    }
+
+    /// Set the key answering this list non-interactively.
+    pub fn answer_key(mut self, key: impl Into<String>) -> Self {
+        self.answer_key = Some(key.into());
+        self
+    }
+}
+
+/// Display a selectable, paginated list in the terminal, with keyboard navigation and numeric input.
+///
+/// # Parameters
+/// - `items`: A slice of items to display. Each item must implement `ListItem`,
+///   which every `ToString` type does.
+/// - `config`: A reference to a `ListConfig` that controls visual layout and colors.
+///
+/// # Returns
+/// Returns `Ok(Some(index))` when the user makes a selection via `Enter`
+/// (either via arrow navigation or numeric input),
+/// `Ok(None)` if the user presses `Esc`,
+/// or an `Err` if a terminal I/O error occurs.
+///
+/// # Features
+/// - Navigate with arrow keys, PageUp/PageDown and Home/End, or the keys
+///   bound in `config.keymap`
+/// - Type numbers to jump to an item directly
+/// - Backspace to edit input buffer
+/// - Realtime visual updates with highlighted selection
+/// - Automatic terminal space management
+/// - With `config.filterable`, typed text fuzzy-filters and ranks the items;
+///   the returned index still refers to the original `items` slice
+/// - Disabled items and separators are drawn with `disabled_fg`, skipped by
+///   navigation and cannot be chosen; separators are not numbered
+/// - The description of the highlighted item is shown below the list
+/// - With `config.preview`, a pane shows the wrapped preview of the highlighted item
+/// - The layout is fitted to the terminal and redrawn when the terminal is resized
+/// - With `config.mouse`, click to highlight, double-click to confirm and
+///   scroll to change pages
+/// - When input or output is not a terminal (e.g. piped), the items are
+///   printed as a numbered listing and the choice is read as a typed number
+///   or label; an empty line picks the first item and end of input cancels
+pub fn choose_from_list<T: ListItem>(items: &[T], config: &ListConfig) -> Result<Option<usize>> {
+    let entries = items.iter().map(|item| Entry::new(item, 0)).collect();
+    Ok(run_list(entries, Vec::new(), config, false)?.and_then(|chosen| chosen.first().copied()))
+}
+
+/// Display a paginated list where any number of items can be checked.
+///
+/// # Parameters
+/// - `items`: A slice of items to display. Each item must implement `ListItem`.
+/// - `config`: A reference to a `ListConfig` that controls layout, colors,
+///   preselected items, selection limits and checkbox markers.
+///
+/// # Returns
+/// Returns `Ok(Some(indices))` with the checked indices in ascending order when
+/// the user confirms with `Enter`, `Ok(None)` if the user presses `Esc`,
+/// or an `Err` if a terminal I/O error occurs.
+///
+/// # Features
+/// - Space toggles the highlighted item, or the item whose number was typed
+/// - Typing a number and pressing `Enter` toggles that item
+/// - Ctrl+A checks every item (or clears them if all are checked) and Ctrl+R
+///   inverts every checkbox; both can be rebound in `config.keymap`
+/// - `Enter` is refused while fewer than `min_selected` items are checked,
+///   and checking more than `max_selected` items is refused
+/// - When input or output is not a terminal, the checked items are typed as
+///   numbers separated by commas; an empty line keeps the preselected items
+pub fn choose_many_from_list<T: ListItem>(
+    items: &[T],
+    config: &ListConfig,
+) -> Result<Option<Vec<usize>>> {
+    let entries = items.iter().map(|item| Entry::new(item, 0)).collect();
+    run_list(entries, Vec::new(), config, true)
+}
+
+/// A titled group of items for [`choose_from_groups`].
+#[derive(Debug, Clone)]
+pub struct ListGroup<T> {
+    /// Title drawn on the group's header row.
+    pub title: String,
+    /// Items in the group.
+    pub items: Vec<T>,
+}
+
+impl<T> ListGroup<T> {
+    /// Create a group with the given title and items.
+    pub fn new(title: impl Into<String>, items: Vec<T>) -> Self {
+        Self {
+            title: title.into(),
+            items,
+        }
+    }
+}
+
+/// Display a list whose items are split into titled groups.
+///
+/// Each group starts on a new row below a header row showing its title.
+/// Items are numbered continuously across groups, and with
+/// `config.page_by_group` PageUp/PageDown jump to the previous/next group.
+/// Filtering keeps matches inside their groups and hides empty groups.
+///
+/// # Returns
+/// Returns `Ok(Some((group, index)))` with the position of the chosen item
+/// inside `groups`, `Ok(None)` if the user presses `Esc`,
+/// or an `Err` if a terminal I/O error occurs.
+pub fn choose_from_groups<T: ListItem>(
+    groups: &[ListGroup<T>],
+    config: &ListConfig,
+) -> Result<Option<(usize, usize)>> {
+    let entries = groups
+        .iter()
+        .enumerate()
+        .flat_map(|(gi, group)| group.items.iter().map(move |item| Entry::new(item, gi)))
+        .collect();
+    let titles = groups.iter().map(|group| group.title.clone()).collect();
+    let chosen = run_list(entries, titles, config, false)?.and_then(|c| c.first().copied());
+
+    // Map the flat index back to (group, index within group)
+    Ok(chosen.and_then(|mut idx| {
+        groups.iter().enumerate().find_map(|(gi, group)| {
+            if idx < group.items.len() {
+                Some((gi, idx))
+            } else {
+                idx -= group.items.len();
+                None
+            }
+        })
+    }))
+}
+
+/// Run the interactive list loop shared by single, multi-select and grouped lists.
+///
+/// `groups` holds the group titles; it is empty for lists without headers.
+/// A preset answer is used without showing the list, and a plain numbered
+/// listing replaces it when input or output is not a terminal.
+fn run_list(
+    entries: Vec<Entry>,
+    groups: Vec<String>,
+    config: &ListConfig,
+    multi: bool,
+) -> Result<Option<Vec<usize>>> {
+    if let Some(chosen) = preset_choice(&entries, config, multi)? {
+        return Ok(Some(chosen));
+    }
+    if !backend::is_terminal() {
+        return plain_list(&entries, &groups, config, multi);
+    }
+    backend::enable_raw_mode()?;
+    let result = event_loop(entries, groups, config, multi);
+    if result.is_err() {
+        // Leave the terminal usable when drawing or reading input failed
+        if config.mouse {
+            let _ = execute!(backend::stdout(), DisableMouseCapture);
+        }
+        let _ = backend::disable_raw_mode();
+    }
+    result
+}
+
+/// Resolve the preset answer for the list, if any (see [`parse_choice`]).
+/// The default is the first selectable item, or the preselected items for
+/// multi-select lists.
+fn preset_choice(
+    entries: &[Entry],
+    config: &ListConfig,
+    multi: bool,
+) -> Result<Option<Vec<usize>>> {
+    let numbers = entry_numbers(entries);
+    let default = default_choice(entries, &numbers, config, multi);
+    let key = config.answer_key.as_deref();
+    let Some(answer) = answers::preset(key, default.as_deref())? else {
+        return Ok(None);
+    };
+    match parse_choice(entries, &numbers, &answer, config, multi) {
+        Ok(chosen) => Ok(Some(chosen)),
+        Err(problem) => Err(answers::invalid_answer(key.unwrap_or_default(), &problem).into()),
+    }
+}
+
+/// Show the list as numbered lines and read the choice as a typed line,
+/// for when input or output is not a terminal. An empty line picks the
+/// default and end of input cancels.
+fn plain_list(
+    entries: &[Entry],
+    groups: &[String],
+    config: &ListConfig,
+    multi: bool,
+) -> Result<Option<Vec<usize>>> {
+    let numbers = entry_numbers(entries);
+    let mut stdout = backend::stdout();
+    let width = numbers
+        .iter()
+        .flatten()
+        .max()
+        .map_or(1, |n| n.to_string().len());
+    for (idx, entry) in entries.iter().enumerate() {
+        let new_group = idx == 0 || entry.group != entries[idx - 1].group;
+        if let (true, Some(title)) = (new_group, groups.get(entry.group)) {
+            writeln!(stdout, "{}:", title)?;
+        }
+        let label: String = entry.spans.iter().map(|span| span.text.as_str()).collect();
+        let Some(number) = numbers[idx] else {
+            writeln!(stdout, "  {:width$}  {}", "", label, width = width)?;
+            continue;
+        };
+        let marker = match multi {
+            true if config.preselected.contains(&idx) => format!("{} ", config.checked_marker),
+            true => format!("{} ", config.unchecked_marker),
+            false => String::new(),
+        };
+        let disabled = if entry.disabled { " (disabled)" } else { "" };
+        writeln!(
+            stdout,
+            "  {:>width$}) {}{}{}",
+            number,
+            marker,
+            label,
+            disabled,
+            width = width
+        )?;
+    }
+
+    let default = default_choice(entries, &numbers, config, multi);
+    let prompt = match (multi, &default) {
+        (true, Some(default)) if !default.is_empty() => {
+            format!("Enter numbers separated by commas [{}]: ", default)
+        }
+        (true, _) => String::from("Enter numbers separated by commas: "),
+        (false, Some(default)) => format!("Enter a number [{}]: ", default),
+        (false, None) => String::from("Enter a number: "),
+    };
+    loop {
+        write!(stdout, "{}", prompt)?;
+        stdout.flush()?;
+        let mut line = String::new();
+        if backend::read_line(&mut line)? == 0 {
+            writeln!(stdout)?;
+            return Ok(None);
+        }
+        let answer = match (line.trim(), &default) {
+            ("", Some(default)) => default.clone(),
+            (answer, _) => answer.to_string(),
+        };
+        match parse_choice(entries, &numbers, &answer, config, multi) {
+            Ok(chosen) => return Ok(Some(chosen)),
+            Err(problem) => writeln!(backend::stderr(), "Error: {}", problem)?,
+        }
+    }
+}
+
+/// Numbers shown for the entries; separators are not numbered.
+fn entry_numbers(entries: &[Entry]) -> Vec<Option<usize>> {
+    let mut next = 0;
+    entries
+        .iter()
+        .map(|entry| {
+            (!entry.separator).then(|| {
+                next += 1;
+                next
+            })
+        })
+        .collect()
+}
+
+/// Default choice as typed: the first selectable item, or the preselected
+/// items for multi-select lists.
+fn default_choice(
+    entries: &[Entry],
+    numbers: &[Option<usize>],
+    config: &ListConfig,
+    multi: bool,
+) -> Option<String> {
+    let number_of = |idx: usize| numbers[idx].map(|num| num.to_string());
+    if multi {
+        let preselected = config
+            .preselected
+            .iter()
+            .filter(|&&idx| idx < entries.len() && entries[idx].selectable())
+            .filter_map(|&idx| number_of(idx));
+        Some(preselected.collect::<Vec<_>>().join(","))
+    } else {
+        entries
+            .iter()
+            .position(Entry::selectable)
+            .and_then(number_of)
+    }
+}
+
+/// Parse a typed choice: an item number or label (ignoring case), or several
+/// separated by commas for multi-select lists. Returns the chosen indices in
+/// ascending order, or the problem with the choice.
+fn parse_choice(
+    entries: &[Entry],
+    numbers: &[Option<usize>],
+    answer: &str,
+    config: &ListConfig,
+    multi: bool,
+) -> std::result::Result<Vec<usize>, String> {
+    let tokens: Vec<&str> = if multi {
+        answer
+            .split(',')
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .collect()
+    } else {
+        vec![answer.trim()]
+    };
+    let mut chosen = Vec::new();
+    for token in tokens {
+        let number = token.parse::<usize>().ok();
+        let found = entries.iter().enumerate().position(|(idx, entry)| {
+            if entry.separator {
+                return false;
+            }
+            let label: String = entry.spans.iter().map(|span| span.text.as_str()).collect();
+            number.is_some_and(|n| numbers[idx] == Some(n))
+                || label.trim().eq_ignore_ascii_case(token)
+        });
+        match found {
+            Some(idx) if entries[idx].selectable() => chosen.push(idx),
+            Some(_) => return Err(format!("'{}' cannot be chosen", token)),
+            None => return Err(format!("'{}' is not in the list", token)),
+        }
+    }
+    chosen.sort_unstable();
+    chosen.dedup();
+
+    let count = chosen.len();
+    if !multi && count != 1 {
+        Err(String::from("Expected one item"))
+    } else if let Some(min) = config.min_selected.filter(|&min| multi && count < min) {
+        Err(format!("Select at least {}", min))
+    } else if let Some(max) = config.max_selected.filter(|&max| multi && count > max) {
+        Err(format!("Select at most {}", max))
+    } else {
+        Ok(chosen)
+    }
+}
+
+/// Draw the list and handle events until it is confirmed or cancelled.
+/// Expects raw mode to be enabled; [`finish`] disables it.
+fn event_loop(
+    entries: Vec<Entry>,
+    groups: Vec<String>,
+    config: &ListConfig,
+    multi: bool,
+) -> Result<Option<Vec<usize>>> {
+    let (start_col, start_row) = backend::cursor_position()?;
+    let mut stdout = backend::stdout();
+
+    let mut state = ListState::new(entries, groups, config, multi);
+    let mut layout = fit_layout(config, &mut state, start_col, backend::size()?);
+    if config.mouse {
+        execute!(stdout, EnableMouseCapture)?;
+    }
+    let mut last_click: Option<(usize, Instant)> = None;
+
+    // Ensure we have enough space in the terminal
+    let mut display_start_row = ensure_display_space(start_row, list_height(&state, &layout))?;
+    render_page(&state, &layout, start_col, display_start_row)?;
+
+    loop {
+        match backend::read_event()? {
+            // Windows also reports key releases; bindings match presses and repeats
+            Event::Key(key) if key.kind != KeyEventKind::Release => {
+                if let Step::Finish(result) = state.handle_key(key, &layout) {
+                    return finish(
+                        &mut stdout,
+                        start_col,
+                        display_start_row,
+                        &state,
+                        &layout,
+                        result,
+                    );
+                }
+            }
+            Event::Mouse(mouse) if config.mouse => {
+                let step = match mouse.kind {
+                    MouseEventKind::Down(MouseButton::Left) => {
+                        let Some(pos) = hit_test(
+                            &state,
+                            &layout,
+                            start_col,
+                            display_start_row,
+                            mouse.column,
+                            mouse.row,
+                        ) else {
+                            continue;
+                        };
+                        let double = last_click.is_some_and(|(last, at)| {
+                            last == pos && at.elapsed() <= DOUBLE_CLICK_INTERVAL
+                        });
+                        last_click = (!double).then(|| (pos, Instant::now()));
+                        state.click(pos, double, &layout)
+                    }
+                    MouseEventKind::ScrollDown => state
+                        .apply(ListAction::PageDown, &layout)
+                        .unwrap_or(Step::Continue),
+                    MouseEventKind::ScrollUp => state
+                        .apply(ListAction::PageUp, &layout)
+                        .unwrap_or(Step::Continue),
+                    _ => continue,
+                };
+                if let Step::Finish(result) = step {
+                    return finish(
+                        &mut stdout,
+                        start_col,
+                        display_start_row,
+                        &state,
+                        &layout,
+                        result,
+                    );
+                }
+            }
+            Event::Resize(width, height) => {
+                // The cursor is parked on the input line; the terminal may have
+                // reflowed, so find the list's top from where the cursor is now
+                let (_, cursor_row) = backend::cursor_position()?;
+                let top = cursor_row.saturating_sub(layout.rows_per_page as u16);
+                execute!(
+                    stdout,
+                    MoveTo(start_col, top),
+                    Clear(ClearType::FromCursorDown)
+                )?;
+                layout = fit_layout(config, &mut state, start_col, (width, height));
+                display_start_row = ensure_display_space(top, list_height(&state, &layout))?;
+            }
+            _ => continue,
+        }
+        render_page(&state, &layout, start_col, display_start_row)?;
+    }
+}
+
+/// Clear the list, restore the terminal and return the list's result.
+fn finish(
+    stdout: &mut impl Write,
+    start_col: u16,
+    start_row: u16,
+    state: &ListState,
+    layout: &ListConfig,
+    result: Option<Vec<usize>>,
+) -> Result<Option<Vec<usize>>> {
+    cleanup(stdout, start_col, start_row, state, layout)?;
+    if layout.mouse {
+        execute!(stdout, DisableMouseCapture)?;
+    }
+    backend::disable_raw_mode()?;
+    Ok(result)
+}
+
+/// Maximum time between two clicks on the same item to count as a double-click.
+const DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(400);
+
+/// Map a mouse position to the view position of the item drawn there, if any.
+///
+/// Uses the same geometry as `render_page`: line `n` of the current page is
+/// drawn at `start_row + n`, and column `c` at `start_col + c * cell_width`.
+fn hit_test(
+    state: &ListState,
+    layout: &ListConfig,
+    start_col: u16,
+    start_row: u16,
+    column: u16,
+    row: u16,
+) -> Option<usize> {
+    let (selected_line, _) = state.locate(state.selected);
+    let page_range = calculate_page_range(selected_line, &state.page_starts);
+    let line = page_range.start + row.checked_sub(start_row)? as usize;
+    if line >= page_range.end || line - page_range.start >= layout.rows_per_page {
+        return None;
+    }
+    let col = (column.checked_sub(start_col)? / layout.cell_width.max(1)) as usize;
+    match state.lines.get(line)? {
+        Line::Items(range) if col < range.len() => Some(range.start + col),
+        _ => None,
+    }
+}
+
+/// Narrowest right-hand preview pane worth drawing; below this it moves to the bottom.
+const MIN_PREVIEW_WIDTH: usize = 10;
+
+/// Fit `config` to a terminal of `size` (columns, rows), returning the
+/// effective configuration used for drawing, and re-split `state` into lines.
+///
+/// Cells never exceed the terminal width, rows and columns are reduced until
+/// the list fits, and a right-hand preview that has no room moves to the bottom.
+/// With `config.auto_layout`, rows and columns are derived from the size instead.
+fn fit_layout(
+    config: &ListConfig,
+    state: &mut ListState,
+    start_col: u16,
+    size: (u16, u16),
+) -> ListConfig {
+    let (width, height) = size;
+    let mut layout = config.clone();
+    let available = width.saturating_sub(start_col).max(1) as usize;
+    layout.cell_width = layout.cell_width.min(available as u16);
+
+    let mut grid_available = available;
+    if layout.preview.is_some() && layout.preview_position == PreviewPosition::Right {
+        let cell = layout.cell_width as usize;
+        if available < cell + 3 + MIN_PREVIEW_WIDTH {
+            layout.preview_position = PreviewPosition::Bottom;
+        } else {
+            let preview_width = (layout.preview_width as usize).min(available - cell - 3);
+            layout.preview_width = preview_width as u16;
+            grid_available = available - 3 - preview_width;
+        }
+    }
+    let max_per_row = (grid_available / layout.cell_width.max(1) as usize).max(1);
+    layout.items_per_row = if config.auto_layout {
+        max_per_row
+    } else {
+        config.items_per_row.min(max_per_row).max(1)
+    };
+
+    // Lines below the grid: input line, description line and bottom preview
+    let mut footer = 1 + has_description_line(state) as u16;
+    if layout.preview.is_some() && layout.preview_position == PreviewPosition::Bottom {
+        let room = height.saturating_sub(footer + 2);
+        layout.preview_height = layout.preview_height.min(room / 2).max(1);
+        footer += 1 + layout.preview_height;
+    }
+    let max_rows = height.saturating_sub(footer).max(1) as usize;
+    layout.rows_per_page = max_rows;
+    state.relayout(&layout);
+    layout.rows_per_page = if config.auto_layout {
+        state.lines.len().clamp(1, max_rows)
+    } else {
+        config.rows_per_page.min(max_rows).max(1)
+    };
+    state.relayout(&layout);
+    layout
+}
+
+/// Outcome of feeding a key press to a [`ListState`].
+enum Step {
+    /// Keep the list open and redraw it.
+    Continue,
+    /// Close the list; `None` means the user cancelled.
+    Finish(Option<Vec<usize>>),
+}
+
+/// One line of the list grid.
+enum Line {
+    /// A header row showing the title of the given group.
+    Header(usize),
+    /// A row of items, as positions in the filtered view.
+    Items(Range<usize>),
+}
+
+/// Everything the list needs from one item, captured when the list opens.
+struct Entry {
+    /// Index of the group the item belongs to.
+    group: usize,
+    spans: Vec<Span>,
+    description: Option<String>,
+    metadata: Option<String>,
+    disabled: bool,
+    separator: bool,
+    filter_text: String,
+    /// True when filter match positions line up with the label characters.
+    highlight_matches: bool,
+}
+
+impl Entry {
+    /// True if the item can be highlighted and chosen.
+    fn selectable(&self) -> bool {
+        !self.disabled && !self.separator
+    }
+
+    fn new<T: ListItem>(item: &T, group: usize) -> Self {
+        let spans = item.spans();
+        let filter_text = item.filter_text();
+        let label: String = spans.iter().map(|span| span.text.as_str()).collect();
+        Self {
+            group,
+            highlight_matches: label == filter_text,
+            description: item.description(),
+            metadata: item.metadata(),
+            disabled: item.is_disabled(),
+            separator: item.is_separator(),
+            spans,
+            filter_text,
+        }
+    }
+}
+
+/// Mutable state of an open list.
+///
+/// Navigation works on `view`, the filtered and ranked list of item indices;
+/// checkboxes and returned indices always refer to the original items.
+struct ListState {
+    /// Every item, in original order.
+    entries: Vec<Entry>,
+    /// Group titles; empty when the list has no headers.
+    groups: Vec<String>,
+    /// Header and item rows of the grid, rebuilt whenever `view` changes.
+    lines: Vec<Line>,
+    /// Index of the first line of every page.
+    page_starts: Vec<usize>,
+    /// Displayed number of every item; `None` for separators.
+    numbers: Vec<Option<usize>>,
+    /// Indices of the visible items, in display order.
+    view: Vec<usize>,
+    /// Matched character positions for each entry of `view`.
+    matches: Vec<Vec<usize>>,
+    /// Position of the highlighted item within `view`.
+    selected: usize,
+    /// Currently typed numeric input.
+    digit_buffer: String,
+    /// Current filter text.
+    query: String,
+    /// Checkbox state per item; `None` for single-selection lists.
+    checked: Option<Vec<bool>>,
+    /// Transient message shown on the input line, e.g. a violated selection limit.
+    message: Option<String>,
+}
+
+impl ListState {
+    fn new(entries: Vec<Entry>, groups: Vec<String>, config: &ListConfig, multi: bool) -> Self {
+        let total = entries.len();
+        let checked = multi.then(|| {
+            let mut checked = vec![false; total];
+            for &idx in config
+                .preselected
+                .iter()
+                .filter(|&&idx| idx < total && entries[idx].selectable())
+            {
+                checked[idx] = true;
+            }
+            checked
+        });
+        let numbers = entry_numbers(&entries);
+        let mut state = Self {
+            entries,
+            groups,
+            lines: Vec::new(),
+            page_starts: Vec::new(),
+            numbers,
+            view: (0..total).collect(),
+            matches: vec![Vec::new(); total],
+            selected: 0,
+            digit_buffer: String::new(),
+            query: String::new(),
+            checked,
+            message: None,
+        };
+        state.relayout(config);
+        state.move_to(0, true);
+        state
+    }
+
+    /// Split the filtered view into grid lines, starting a new row and
+    /// inserting a header whenever the group changes.
+    fn relayout(&mut self, config: &ListConfig) {
+        self.lines.clear();
+        let mut row_start = 0;
+        for pos in 0..self.view.len() {
+            let group = self.entries[self.view[pos]].group;
+            let new_group = pos == 0 || group != self.entries[self.view[pos - 1]].group;
+            if new_group || pos - row_start == config.items_per_row {
+                if pos > row_start {
+                    self.lines.push(Line::Items(row_start..pos));
+                }
+                row_start = pos;
+            }
+            if new_group && !self.groups.is_empty() {
+                self.lines.push(Line::Header(group));
+            }
+        }
+        if self.view.len() > row_start {
+            self.lines.push(Line::Items(row_start..self.view.len()));
+        }
+
+        // Break pages every `rows_per_page` lines, moving a header that would
+        // end a page onto the next one so it stays above its items
+        self.page_starts = vec![0];
+        let mut page_start = 0;
+        for (li, line) in self.lines.iter().enumerate().skip(1) {
+            let used = li - page_start;
+            let orphan = matches!(line, Line::Header(_))
+                && used + 1 == config.rows_per_page
+                && li + 1 < self.lines.len();
+            if used == config.rows_per_page || orphan {
+                self.page_starts.push(li);
+                page_start = li;
+            }
+        }
+    }
+
+    /// Line index and column of view position `pos`.
+    fn locate(&self, pos: usize) -> (usize, usize) {
+        self.lines
+            .iter()
+            .enumerate()
+            .find_map(|(li, line)| match line {
+                Line::Items(range) if range.contains(&pos) => Some((li, pos - range.start)),
+                _ => None,
+            })
+            .unwrap_or((0, 0))
+    }
+
+    /// View position in column `col` (or the last item of a shorter row) of the
+    /// first item row at or after line `target`, or at or before it if `!forward`.
+    fn position_near_line(&self, target: usize, col: usize, forward: bool) -> Option<usize> {
+        let pick = |line: &Line| match line {
+            Line::Items(range) => Some(range.start + col.min(range.len() - 1)),
+            Line::Header(_) => None,
+        };
+        if forward {
+            self.lines.get(target..)?.iter().find_map(pick)
+        } else {
+            self.lines.get(..=target)?.iter().rev().find_map(pick)
+        }
+    }
+
+    /// Group of the item at view position `pos`.
+    fn group_at(&self, pos: usize) -> usize {
+        self.view.get(pos).map_or(0, |&idx| self.entries[idx].group)
+    }
+
+    /// First view position of the previous (`!forward`) or next group.
+    fn adjacent_group_start(&self, forward: bool) -> Option<usize> {
+        let group = self.group_at(self.selected);
+        let target = if forward {
+            self.view
+                .iter()
+                .map(|&idx| self.entries[idx].group)
+                .find(|&g| g > group)?
+        } else {
+            self.view
+                .iter()
+                .map(|&idx| self.entries[idx].group)
+                .filter(|&g| g < group)
+                .max()
+                .unwrap_or(group)
+        };
+        (0..self.view.len()).find(|&pos| self.group_at(pos) == target)
+    }
+
+    /// Original index of the highlighted item, if any item is visible.
+    fn current(&self) -> Option<usize> {
+        self.view.get(self.selected).copied()
+    }
+
+    /// Move the highlight to view position `target`, or to the nearest
+    /// selectable item beyond it in the direction of travel.
+    /// The highlight stays put if there is no such item.
+    fn move_to(&mut self, target: usize, forward: bool) {
+        let selectable = |pos: &usize| self.entries[self.view[*pos]].selectable();
+        let found = if forward {
+            (target..self.view.len()).find(selectable)
+        } else {
+            (0..=target.min(self.view.len().saturating_sub(1)))
+                .rev()
+                .find(selectable)
+        };
+        if let Some(pos) = found {
+            self.selected = pos;
+        }
+    }
+
+    /// Apply a key press and report whether the list should close.
+    ///
+    /// Keys bound in `config.keymap` are tried first; unbound keys (and bound
+    /// ones whose action does not apply, like Space in a single-select list)
+    /// edit the typed number or the filter query.
+    fn handle_key(&mut self, key: KeyEvent, config: &ListConfig) -> Step {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        self.message = None;
+
+        if let Some(step) = config
+            .keymap
+            .get(key)
+            .and_then(|action| self.apply(action, config))
+        {
+            return step;
+        }
+
+        match key.code {
+            KeyCode::Char(c) if c.is_ascii_digit() && self.query.is_empty() => {
+                self.digit_buffer.push(c)
+            }
+            KeyCode::Char(c) if config.filterable && !ctrl => {
+                self.digit_buffer.clear();
+                self.query.push(c);
+                self.refilter(config);
+            }
+            KeyCode::Backspace if !self.digit_buffer.is_empty() => {
+                self.digit_buffer.pop();
+            }
+            KeyCode::Backspace if !self.query.is_empty() => {
+                self.query.pop();
+                self.refilter(config);
+            }
+            _ => {}
+        }
+        Step::Continue
+    }
+
+    /// Perform `action`, returning `None` if it does not apply to this list.
+    fn apply(&mut self, action: ListAction, config: &ListConfig) -> Option<Step> {
+        let visible = self.view.len();
+        let (line, col) = self.locate(self.selected);
+        let by_group = config.page_by_group && !self.groups.is_empty();
+        let multi = self.checked.is_some();
+
+        let target = match action {
+            ListAction::Confirm => return Some(self.confirm(config)),
+            ListAction::Cancel => return Some(Step::Finish(None)),
+            ListAction::Toggle if multi => {
+                let target = if self.digit_buffer.is_empty() {
+                    self.current()
+                } else {
+                    self.typed_index()
+                };
+                self.digit_buffer.clear();
+                match target {
+                    Some(idx) => self.toggle(idx, config),
+                    None => self.message = Some(String::from("No such item")),
+                }
+                return Some(Step::Continue);
+            }
+            ListAction::SelectAll if multi => {
+                self.select_all(config);
+                return Some(Step::Continue);
+            }
+            ListAction::Invert if multi => {
+                self.invert(config);
+                return Some(Step::Continue);
+            }
+            ListAction::Toggle | ListAction::SelectAll | ListAction::Invert => return None,
+            ListAction::Left => self.selected.checked_sub(1).map(|pos| (pos, false)),
+            ListAction::Right => (self.selected + 1 < visible).then(|| (self.selected + 1, true)),
+            ListAction::Up => line
+                .checked_sub(1)
+                .and_then(|target| self.position_near_line(target, col, false))
+                .map(|pos| (pos, false)),
+            ListAction::Down => self
+                .position_near_line(line + 1, col, true)
+                .map(|pos| (pos, true)),
+            ListAction::PageDown if by_group => {
+                self.adjacent_group_start(true).map(|pos| (pos, true))
+            }
+            ListAction::PageUp if by_group => {
+                self.adjacent_group_start(false).map(|pos| (pos, true))
+            }
+            ListAction::PageDown => (line + config.rows_per_page < self.lines.len())
+                .then(|| self.position_near_line(line + config.rows_per_page, col, true))
+                .flatten()
+                .map(|pos| (pos, true)),
+            ListAction::PageUp => line
+                .checked_sub(config.rows_per_page)
+                .and_then(|target| {
+                    self.position_near_line(target, col, false)
+                        .or_else(|| self.position_near_line(target, col, true))
+                })
+                .map(|pos| (pos, false)),
+            ListAction::First => (visible > 0).then_some((0, true)),
+            ListAction::Last => visible.checked_sub(1).map(|pos| (pos, false)),
+        };
+        if let Some((pos, forward)) = target {
+            self.digit_buffer.clear();
+            self.move_to(pos, forward);
+        }
+        Some(Step::Continue)
+    }
+
+    /// Handle a click on view position `pos`: highlight the item and, in
+    /// multi-select lists, toggle it. The second click of a double-click checks
+    /// the item if needed and confirms.
+    fn click(&mut self, pos: usize, double: bool, config: &ListConfig) -> Step {
+        let idx = self.view[pos];
+        if !self.entries[idx].selectable() {
+            return Step::Continue;
+        }
+        self.digit_buffer.clear();
+        self.message = None;
+        self.selected = pos;
+        let is_checked = |state: &Self| state.checked.as_ref().map(|checked| checked[idx]);
+        // A double-click confirms with the item checked, whatever the first
+        // click toggled it to
+        if !double || is_checked(self) == Some(false) {
+            self.toggle(idx, config);
+        }
+        if double && is_checked(self) != Some(false) {
+            return self.confirm(config);
+        }
+        Step::Continue
+    }
+
+    /// Rebuild `view` from the current query, best matches first within each group.
+    fn refilter(&mut self, config: &ListConfig) {
+        let mut ranked: Vec<(usize, i64, Vec<usize>)> = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| self.query.is_empty() || !entry.separator)
+            .filter_map(|(idx, entry)| {
+                fuzzy_match(&self.query, &entry.filter_text).map(|m| (idx, m.score, m.positions))
+            })
+            .collect();
+        if !self.query.is_empty() {
+            ranked.sort_by_key(|&(idx, score, _)| {
+                (
+                    self.entries[idx].group,
+                    std::cmp::Reverse(score),
+                    self.entries[idx].filter_text.len(),
+                    idx,
+                )
+            });
+        }
+        self.view = ranked.iter().map(|&(idx, _, _)| idx).collect();
+        self.matches = ranked
+            .into_iter()
+            .map(|(_, _, positions)| positions)
+            .collect();
+        self.selected = 0;
+        self.relayout(config);
+        self.move_to(0, true);
+    }
+
+    /// Handle `Enter`: pick the item in single mode, confirm the checked set in multi mode.
+    fn confirm(&mut self, config: &ListConfig) -> Step {
+        if self.checked.is_none() {
+            if !self.digit_buffer.is_empty() {
+                return match self.typed_index() {
+                    Some(idx) if !self.entries[idx].selectable() => {
+                        self.digit_buffer.clear();
+                        self.message = Some(String::from("Item is disabled"));
+                        Step::Continue
+                    }
+                    choice => Step::Finish(choice.map(|idx| vec![idx])),
+                };
+            }
+            return match self.current() {
+                Some(idx) if !self.entries[idx].selectable() => {
+                    self.message = Some(String::from("Item is disabled"));
+                    Step::Continue
+                }
+                Some(idx) => Step::Finish(Some(vec![idx])),
+                None => Step::Continue,
+            };
+        }
+
+        if !self.digit_buffer.is_empty() {
+            match self.typed_index() {
+                Some(idx) => self.toggle(idx, config),
+                None => self.message = Some(String::from("No such item")),
+            }
+            self.digit_buffer.clear();
+            return Step::Continue;
+        }
+
+        let chosen = self.checked_indices();
+        if let Some(min) = config.min_selected {
+            if chosen.len() < min {
+                self.message = Some(format!("Select at least {}", min));
+                return Step::Continue;
+            }
+        }
+        // Preselected items may exceed the limit that toggling enforces
+        if let Some(max) = config.max_selected {
+            if chosen.len() > max {
+                self.message = Some(format!("Select at most {}", max));
+                return Step::Continue;
+            }
+        }
+        Step::Finish(Some(chosen))
+    }
+
+    /// Index named by the typed number, if it refers to an existing item.
+    fn typed_index(&self) -> Option<usize> {
+        let number = self.digit_buffer.parse::<usize>().ok()?;
+        self.numbers.iter().position(|&num| num == Some(number))
+    }
+
+    fn checked_indices(&self) -> Vec<usize> {
+        self.checked
+            .iter()
+            .flatten()
+            .enumerate()
+            .filter_map(|(idx, &on)| on.then_some(idx))
+            .collect()
+    }
+
+    /// Flip one checkbox, refusing disabled items and exceeding `max_selected`.
+    fn toggle(&mut self, idx: usize, config: &ListConfig) {
+        let count = self.checked_indices().len();
+        let Some(checked) = self.checked.as_mut() else {
+            return;
+        };
+        if !self.entries[idx].selectable() {
+            self.message = Some(String::from("Item is disabled"));
+            return;
+        }
+        if let Some(max) = config
+            .max_selected
+            .filter(|&max| !checked[idx] && count >= max)
+        {
+            self.message = Some(format!("Select at most {}", max));
+            return;
+        }
+        checked[idx] = !checked[idx];
+    }
+
+    /// Check every enabled item, or clear them all if they are already checked.
+    fn select_all(&mut self, config: &ListConfig) {
+        let enabled = self
+            .entries
+            .iter()
+            .filter(|entry| entry.selectable())
+            .count();
+        let Some(checked) = self.checked.as_mut() else {
+            return;
+        };
+        let all_checked = checked
+            .iter()
+            .zip(&self.entries)
+            .all(|(&on, entry)| on || !entry.selectable());
+        if all_checked {
+            checked.fill(false);
+        } else if let Some(max) = config.max_selected.filter(|&max| enabled > max) {
+            self.message = Some(format!("Select at most {}", max));
+        } else {
+            for (on, entry) in checked.iter_mut().zip(&self.entries) {
+                *on = entry.selectable();
+            }
+        }
+    }
+
+    /// Invert every enabled checkbox, refusing to exceed `max_selected`.
+    fn invert(&mut self, config: &ListConfig) {
+        let enabled = self
+            .entries
+            .iter()
+            .filter(|entry| entry.selectable())
+            .count();
+        let inverted = enabled - self.checked_indices().len();
+        if let Some(max) = config.max_selected.filter(|&max| inverted > max) {
+            self.message = Some(format!("Select at most {}", max));
+            return;
+        }
+        if let Some(checked) = self.checked.as_mut() {
+            for (on, entry) in checked.iter_mut().zip(&self.entries) {
+                *on = !*on && entry.selectable();
+            }
+        }
+    }
+}
+
+/// Ensure there's enough space in the terminal to display the list.
+/// If not enough space, create additional lines by printing newlines.
+///
+/// # Parameters
+/// - `current_row`: Current cursor row position
+/// - `required_lines`: Number of lines the list occupies
+///
+/// # Returns
+/// The row where the list should start displaying
+fn ensure_display_space(current_row: u16, required_lines: u16) -> Result<u16> {
+    let mut stdout = backend::stdout();
+    let (_, terminal_height) = backend::size()?;
+
+    let available_lines = terminal_height.saturating_sub(current_row);
+
+    if available_lines < required_lines {
+        // Need to create more space
+        let lines_to_create = required_lines - available_lines;
+
+        // Print newlines to create space and scroll the terminal
+        for _ in 0..lines_to_create {
+            execute!(stdout, Print("\n"))?;
+        }
+        stdout.flush()?;
+
+        // Get new position after creating space
+        let (_, new_row) = backend::cursor_position()?;
+        // The display should start from a position that leaves enough space
+        Ok(new_row.saturating_sub(required_lines))
+    } else {
+        // Enough space available, use current position
+        Ok(current_row)
+    }
+}
+
+/// Number of terminal lines the list occupies: the grid rows, the input line
+/// and, if any item has one, the description line.
+fn list_height(state: &ListState, config: &ListConfig) -> u16 {
+    let height = config.rows_per_page as u16 + 1 + has_description_line(state) as u16;
+    match bottom_preview_lines(config) {
+        // A rule line separates the preview from the list
+        Some(lines) => height + 1 + lines,
+        None => height,
+    }
+}
+
+/// True if any item has a description, which reserves a line below the input line.
+fn has_description_line(state: &ListState) -> bool {
+    state
+        .entries
+        .iter()
+        .any(|entry| entry.description.is_some())
+}
+
+/// Height of the bottom preview pane, if one is configured.
+fn bottom_preview_lines(config: &ListConfig) -> Option<u16> {
+    config
+        .preview
+        .as_ref()
+        .filter(|_| config.preview_position == PreviewPosition::Bottom)
+        .map(|_| config.preview_height)
+}
+
+/// Total width of the list in characters, including a right-hand preview pane.
+fn list_width(config: &ListConfig) -> usize {
+    match config.preview {
+        // The pane is drawn after a " │ " divider
+        Some(_) if config.preview_position == PreviewPosition::Right => {
+            grid_width(config) + 3 + config.preview_width as usize
+        }
+        _ => grid_width(config),
+    }
+}
+
+/// Compute the range of lines on the current page based on the highlighted line.
+///
+/// Lines include group header rows, and a page may end early so that a header
+/// is never its last line; `page_starts` holds the first line of every page.
+fn calculate_page_range(selected_line: usize, page_starts: &[usize]) -> Range<usize> {
+    let page = page_starts
+        .iter()
+        .rposition(|&start| start <= selected_line)
+        .unwrap_or(0);
+    let start = page_starts.get(page).copied().unwrap_or(0);
+    let end = page_starts.get(page + 1).copied().unwrap_or(usize::MAX);
+    start..end
+}
+
+/// Total width of the item grid in characters.
+fn grid_width(config: &ListConfig) -> usize {
+    config.items_per_row * config.cell_width as usize
+}
+
+/// Clear the displayed list from the terminal.
+///
+/// Used to clean up the screen after the list UI is dismissed.
+fn cleanup(
+    stdout: &mut impl Write,
+    start_col: u16,
+    start_row: u16,
+    state: &ListState,
+    config: &ListConfig,
+) -> anyhow::Result<()> {
+    for row in 0..list_height(state, config) {
+        execute!(
+            stdout,
+            MoveTo(start_col, start_row + row),
+            Print(" ".repeat(list_width(config)))
+        )?;
+    }
+    execute!(
+        stdout,
+        MoveTo(start_col, start_row + config.rows_per_page as u16)
+    )?;
+    Ok(())
+}
+
+/// Render the current page of items to the terminal, with selection and optional digit input.
+///
+/// # Parameters
+/// - `state`: Items, filtered view, highlighted item, typed input, checkboxes and message
+/// - `config`: Layout and color configuration
+/// - `start_col`: Starting column position in the terminal
+/// - `start_row`: Starting row position in the terminal
+fn render_page(
+    state: &ListState,
+    config: &ListConfig,
+    start_col: u16,
+    start_row: u16,
+) -> Result<()> {
+    let mut stdout = backend::stdout();
+    let (selected_line, _) = state.locate(state.selected);
+    let page_range = calculate_page_range(selected_line, &state.page_starts);
+
+    // Clear previous content
+    for row in 0..config.rows_per_page {
+        execute!(
+            stdout,
+            MoveTo(start_col, start_row + row as u16),
+            Print(" ".repeat(grid_width(config)))
+        )?;
+    }
+
+    // Draw group headers and items
+    let page = state
+        .lines
+        .iter()
+        .take(page_range.end)
+        .skip(page_range.start);
+    for (row, line) in page.enumerate() {
+        let y = start_row + row as u16;
+        match line {
+            Line::Header(group) => {
+                let title: String = state.groups[*group]
+                    .chars()
+                    .take(grid_width(config))
+                    .collect();
+                execute!(
+                    stdout,
+                    MoveTo(start_col, y),
+                    PrintStyledContent(config.header_fg.apply(title))
+                )?;
+            }
+            Line::Items(range) => {
+                for (col, pos) in range.clone().enumerate() {
+                    let x = start_col + col as u16 * config.cell_width;
+                    execute!(stdout, MoveTo(x, y))?;
+                    render_cell(&mut stdout, state, config, pos, state.view[pos])?;
+                }
+            }
+        }
+    }
+
+    // Draw digit input buffer, or the filter and status when nothing is typed
+    let input_row = start_row + config.rows_per_page as u16;
+    execute!(
+        stdout,
+        MoveTo(start_col, input_row),
+        Print(" ".repeat(grid_width(config))),
+        MoveTo(start_col, input_row)
+    )?;
+    let mut status = Vec::new();
+    let mut style = config.normal_fg;
+    if !state.digit_buffer.is_empty() {
+        status.push(format!("Input: {}", state.digit_buffer));
+    } else if let Some(message) = &state.message {
+        status.push(message.clone());
+        style = config.message_fg;
+    } else {
+        if !state.query.is_empty() {
+            status.push(format!(
+                "Filter: {} ({}/{})",
+                state.query,
+                state.view.len(),
+                state.entries.len()
+            ));
+        }
+        if state.checked.is_some() {
+            status.push(format!("Selected: {}", state.checked_indices().len()));
+        }
+    }
+    if !status.is_empty() {
+        execute!(stdout, PrintStyledContent(style.apply(status.join("  "))))?;
+    }
+
+    // Draw the description of the highlighted item
+    if has_description_line(state) {
+        let description = state
+            .current()
+            .and_then(|idx| state.entries[idx].description.as_deref())
+            .unwrap_or_default();
+        execute!(
+            stdout,
+            MoveTo(start_col, input_row + 1),
+            Print(" ".repeat(grid_width(config))),
+            MoveTo(start_col, input_row + 1),
+            PrintStyledContent(
+                config.normal_fg.apply(
+                    description
+                        .chars()
+                        .take(grid_width(config))
+                        .collect::<String>()
+                )
+            )
+        )?;
+    }
+
+    if let Some(preview) = &config.preview {
+        let text = state
+            .current()
+            .map(|idx| preview.render(idx))
+            .unwrap_or_default();
+        let footer_lines = 1 + has_description_line(state) as u16;
+        render_preview(
+            &mut stdout,
+            &text,
+            config,
+            start_col,
+            start_row,
+            footer_lines,
+        )?;
+    }
+
+    execute!(stdout, ResetColor, MoveTo(start_col, input_row))?;
+    stdout.flush()?;
+    Ok(())
+}
+
+/// Draw a single item at the current cursor position.
+///
+/// `pos` is the item's position in the filtered view, `global` its index in `items`.
+fn render_cell(
+    stdout: &mut impl Write,
+    state: &ListState,
+    config: &ListConfig,
+    pos: usize,
+    global: usize,
+) -> Result<()> {
+    let entry = &state.entries[global];
+    if entry.separator {
+        let label: String = entry.spans.iter().map(|span| span.text.as_str()).collect();
+        let line = if label.is_empty() {
+            String::new()
+        } else {
+            format!("── {} ", label)
+        };
+        let width = (config.cell_width as usize).saturating_sub(1);
+        let line: String = line
+            .chars()
+            .chain(std::iter::repeat('─'))
+            .take(width)
+            .collect();
+        execute!(stdout, PrintStyledContent(config.disabled_fg.apply(line)))?;
+        return Ok(());
+    }
+
+    let style = if entry.disabled {
+        config.disabled_fg
+    } else if pos == state.selected {
+        config.highlight_fg
+    } else {
+        config.normal_fg
+    };
+    let marker = match &state.checked {
+        Some(checked) if checked[global] => format!("{} ", config.checked_marker),
+        Some(_) => format!("{} ", config.unchecked_marker),
+        None => String::new(),
+    };
+    let number = state.numbers[global].unwrap_or_default();
+    let prefix = format!("{marker}{number:>2}. ");
+    execute!(stdout, PrintStyledContent(style.apply(&prefix)))?;
+
+    // Reserve room for right-aligned metadata, separated by a space
+    let mut width = (config.cell_width as usize).saturating_sub(prefix.chars().count());
+    let metadata = entry
+        .metadata
+        .as_deref()
+        .filter(|meta| meta.chars().count() < width);
+    if let Some(meta) = metadata {
+        width -= meta.chars().count() + 1;
+    }
+
+    // Print the label, highlighting the characters matched by the filter
+    let matched: &[usize] = if entry.highlight_matches {
+        &state.matches[pos]
+    } else {
+        &[]
+    };
+    let chars = entry
+        .spans
+        .iter()
+        .flat_map(|span| span.text.chars().map(move |ch| (ch, span.fg)));
+    let mut used = 0;
+    for (ci, (ch, span_fg)) in chars.take(width).enumerate() {
+        let char_style = match span_fg {
+            _ if entry.disabled => style,
+            _ if matched.contains(&ci) => config.match_fg,
+            Some(color) => style.fg(color),
+            None => style,
+        };
+        execute!(stdout, PrintStyledContent(char_style.apply(ch)))?;
+        used += 1;
+    }
+
+    if let Some(meta) = metadata {
+        execute!(
+            stdout,
+            PrintStyledContent(style.apply(format!(
+                "{:>pad$}",
+                meta,
+                pad = width - used + 1 + meta.chars().count()
+            )))
+        )?;
+    }
+    Ok(())
+}
+
+/// Draw the preview pane with `text` wrapped to the pane width.
+///
+/// `footer_lines` is the number of lines between the grid and a bottom pane
+/// (the input line and the optional description line).
+fn render_preview(
+    stdout: &mut impl Write,
+    text: &str,
+    config: &ListConfig,
+    start_col: u16,
+    start_row: u16,
+    footer_lines: u16,
+) -> Result<()> {
+    let (x, y, width, height) = match config.preview_position {
+        PreviewPosition::Right => {
+            let x = start_col + grid_width(config) as u16;
+            for row in 0..config.rows_per_page as u16 {
+                execute!(
+                    stdout,
+                    MoveTo(x, start_row + row),
+                    PrintStyledContent(config.disabled_fg.apply(" │ "))
+                )?;
+            }
+            (
+                x + 3,
+                start_row,
+                config.preview_width as usize,
+                config.rows_per_page as u16,
+            )
+        }
+        PreviewPosition::Bottom => {
+            let rule_row = start_row + config.rows_per_page as u16 + footer_lines;
+            execute!(
+                stdout,
+                MoveTo(start_col, rule_row),
+                PrintStyledContent(config.disabled_fg.apply("─".repeat(grid_width(config))))
+            )?;
+            (
+                start_col,
+                rule_row + 1,
+                grid_width(config),
+                config.preview_height,
+            )
+        }
+    };
+
+    // Wrap each line separately so the preview keeps its own line breaks
+    let lines = text.lines().flat_map(|line| {
+        if line.trim().is_empty() {
+            vec![String::new()]
+        } else {
+            wrap_text(line, width)
+        }
+    });
+    let mut lines = lines.map(|line| line.chars().take(width).collect::<String>());
+    for row in 0..height {
+        let line = lines.next().unwrap_or_default();
+        execute!(
+            stdout,
+            MoveTo(x, y + row),
+            PrintStyledContent(config.normal_fg.apply(format!("{:<width$}", line)))
+        )?;
+    }
+    Ok(())
+}
@@ -0,0 +1,152 @@
+//! Text styles: foreground and background colors plus attributes.
+//!
+//! Every color field of [`InputConfig`](crate::io::input::InputConfig),
+//! [`OutputConfig`](crate::io::output::OutputConfig) and
+//! [`ListConfig`](crate::list::ListConfig) holds a [`Style`]. A plain
+//! [`Color`] converts into a style with just that foreground, so builders
+//! accept either.
+//!
+//! # Example
+//!
+//! ```rust
+//! use prettui::color::Color;
+//! use prettui::list::ListConfig;
+//! use prettui::style::Style;
+//!
+//! // Reverse-video highlight instead of a different foreground
+//! let config = ListConfig::default()
+//!     .highlight_fg(Style::new().reverse().bold())
+//!     .normal_fg(Color::Grey);
+//! assert!(config.highlight_fg.reverse);
+//! assert_eq!(config.normal_fg, Style::new().fg(Color::Grey));
+//! ```
+
+use crate::color::Color;
+use crossterm::style::{Attribute, ContentStyle, StyledContent};
+use std::fmt::Display;
+
+/// Foreground and background colors and text attributes.
+///
+/// `None` colors keep the terminal's default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Style {
+    /// Foreground (text) color.
+    pub fg: Option<Color>,
+    /// Background color.
+    pub bg: Option<Color>,
+    /// Bold (increased intensity) text.
+    pub bold: bool,
+    /// Dim (decreased intensity) text.
+    pub dim: bool,
+    /// Italic text.
+    pub italic: bool,
+    /// Underlined text.
+    pub underline: bool,
+    /// Swapped foreground and background colors.
+    pub reverse: bool,
+    /// Crossed-out text.
+    pub strikethrough: bool,
+}
+
+impl Style {
+    /// A style without colors or attributes.
+    pub const fn new() -> Self {
+        Self {
+            fg: None,
+            bg: None,
+            bold: false,
+            dim: false,
+            italic: false,
+            underline: false,
+            reverse: false,
+            strikethrough: false,
+        }
+    }
+
+    /// Set the foreground color.
+    pub const fn fg(mut self, color: Color) -> Self {
+        self.fg = Some(color);
+        self
+    }
+
+    /// Set the background color.
+    pub const fn bg(mut self, color: Color) -> Self {
+        self.bg = Some(color);
+        self
+    }
+
+    /// Make the text bold.
+    pub const fn bold(mut self) -> Self {
+        self.bold = true;
+        self
+    }
+
+    /// Make the text dim.
+    pub const fn dim(mut self) -> Self {
+        self.dim = true;
+        self
+    }
+
+    /// Make the text italic.
+    pub const fn italic(mut self) -> Self {
+        self.italic = true;
+        self
+    }
+
+    /// Underline the text.
+    pub const fn underline(mut self) -> Self {
+        self.underline = true;
+        self
+    }
+
+    /// Swap the foreground and background colors.
+    pub const fn reverse(mut self) -> Self {
+        self.reverse = true;
+        self
+    }
+
+    /// Cross out the text.
+    pub const fn strikethrough(mut self) -> Self {
+        self.strikethrough = true;
+        self
+    }
+
+    /// `content` in this style, to print with crossterm's `PrintStyledContent`;
+    /// the terminal is reset to its default style after it.
+    pub fn apply<D: Display>(self, content: D) -> StyledContent<D> {
+        StyledContent::new(self.into(), content)
+    }
+}
+
+impl From<Color> for Style {
+    fn from(color: Color) -> Self {
+        Self::new().fg(color)
+    }
+}
+
+impl From<Style> for ContentStyle {
+    fn from(style: Style) -> Self {
+        let mut content = ContentStyle {
+            foreground_color: style.fg.map(Into::into),
+            background_color: style.bg.map(Into::into),
+            ..ContentStyle::default()
+        };
+        let attributes = [
+            (style.bold, Attribute::Bold),
+            (style.dim, Attribute::Dim),
+            (style.italic, Attribute::Italic),
+            (style.underline, Attribute::Underlined),
+            (style.reverse, Attribute::Reverse),
+            (style.strikethrough, Attribute::CrossedOut),
+        ];
+        for (_, attribute) in attributes.into_iter().filter(|(set, _)| *set) {
+            content.attributes.set(attribute);
+        }
+        content
+    }
+}
@@ -0,0 +1,230 @@
+//! Themes: the styles shared by every prompt, list and output function.
+//!
+//! A [`Theme`] gives a [`Style`] to each semantic role. The default
+//! configurations take their styles from the installed theme:
+//!
+//! | Role        | Used for                                                          |
+//! |-------------|-------------------------------------------------------------------|
+//! | `prompt`    | prompt text (`InputConfig::prompt_color`)                         |
+//! | `prefix`    | prefixes and log level tags, list group headers                  |
+//! | `error`     | rejected input and its message (`InputConfig::invalid_color`)     |
+//! | `success`   | input passing live validation (`InputConfig::valid_color`)        |
+//! | `warning`   | list messages such as refused selections (`ListConfig::message_fg`) |
+//! | `highlight` | the highlighted list item and completion, filter matches (underlined) |
+//! | `muted`     | disabled list items, separators and preview borders               |
+//!
+//! Plain text (typed input, output messages and list items) keeps the
+//! terminal's default color.
+//!
+//! Configurations read the theme when they are created with `Default`, so
+//! install the theme first; fields set explicitly keep their value.
+//!
+//! # Example
+//!
+//! ```rust
+//! use prettui::color::Color;
+//! use prettui::io::input::InputConfig;
+//! use prettui::style::Style;
+//! use prettui::theme::{self, Theme};
+//!
+//! Theme {
+//!     highlight: Style::new().reverse(),
+//!     ..Theme::light()
+//! }
+//! .install();
+//!
+//! let cfg = InputConfig {
+//!     prefix_color: Color::Magenta.into(),
+//!     ..Default::default()
+//! };
+//! assert_eq!(cfg.prefix_color, Style::new().fg(Color::Magenta));
+//! assert_eq!(cfg.prompt_color, Theme::light().prompt);
+//! assert_eq!(theme::current().highlight, Style::new().reverse());
+//! ```
+
+use crate::color::Color;
+use crate::style::Style;
+#[cfg(feature = "serde")]
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+#[cfg(feature = "serde")]
+use std::{env, fs, io};
+
+/// Theme installed with [`Theme::install`].
+static INSTALLED: RwLock<Theme> = RwLock::new(Theme::dark());
+
+/// Styles for the semantic roles of prompts, lists and output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
+pub struct Theme {
+    /// Prompt text.
+    pub prompt: Style,
+    /// Prefixes, log level tags and group headers.
+    pub prefix: Style,
+    /// Errors and rejected input.
+    pub error: Style,
+    /// Accepted input.
+    pub success: Style,
+    /// Warnings and refused actions.
+    pub warning: Style,
+    /// The highlighted item or candidate.
+    pub highlight: Style,
+    /// Secondary text: disabled items, separators and borders.
+    pub muted: Style,
+}
+
+impl Theme {
+    /// Bright colors for terminals with a dark background; the default.
+    pub const fn dark() -> Self {
+        Self {
+            prompt: Style::new().fg(Color::Cyan),
+            prefix: Style::new().fg(Color::Blue),
+            error: Style::new().fg(Color::Red),
+            success: Style::new().fg(Color::Green),
+            warning: Style::new().fg(Color::Yellow),
+            highlight: Style::new().fg(Color::Yellow).bold(),
+            muted: Style::new().fg(Color::DarkGrey),
+        }
+    }
+
+    /// Dark colors for terminals with a light background.
+    pub const fn light() -> Self {
+        Self {
+            prompt: Style::new().fg(Color::DarkCyan),
+            prefix: Style::new().fg(Color::DarkBlue),
+            error: Style::new().fg(Color::DarkRed),
+            success: Style::new().fg(Color::DarkGreen),
+            warning: Style::new().fg(Color::DarkYellow),
+            highlight: Style::new().fg(Color::DarkMagenta).bold(),
+            muted: Style::new().fg(Color::DarkGrey),
+        }
+    }
+
+    /// Load a theme from a `.toml` or `.json` file; see [`Theme::from_toml`].
+    ///
+    /// # Errors
+    /// Returns an `io::Error` if the file cannot be read, has another
+    /// extension, or cannot be parsed.
+    #[cfg(feature = "serde")]
+    pub fn from_file(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path)?;
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => Self::from_toml(&text),
+            Some("json") => Self::from_json(&text),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("Unsupported theme file: {}", path.display()),
+            )),
+        }
+    }
+
+    /// Parse a theme from a TOML document with a table per role. Roles and
+    /// fields left out keep their [`Theme::dark`] value, and colors are names
+    /// (`dark_grey`), CSS names, hex strings (`#1e90ff`) or 256-color indexes.
+    ///
+    /// ```rust
+    /// use prettui::color::Color;
+    /// use prettui::style::Style;
+    /// use prettui::theme::Theme;
+    ///
+    /// let theme = Theme::from_toml(
+    ///     r##"
+    /// prompt = { fg = "#ff8700" }
+    /// highlight = { fg = "black", bg = "gold", bold = true }
+    /// muted = { fg = 244 }
+    /// "##,
+    /// )?;
+    /// assert_eq!(theme.prompt, Style::new().fg(Color::Rgb { r: 255, g: 135, b: 0 }));
+    /// assert_eq!(theme.highlight.bg, Color::from_css_name("gold"));
+    /// assert_eq!(theme.muted, Style::new().fg(Color::Ansi256(244)));
+    /// assert_eq!(theme.error, Theme::dark().error);
+    /// # std::io::Result::Ok(())
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an `InvalidData` error if the document cannot be parsed.
+    #[cfg(feature = "serde")]
+    pub fn from_toml(text: &str) -> io::Result<Self> {
+        toml::from_str(text).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    /// Parse a theme from a JSON object laid out like the TOML document of
+    /// [`Theme::from_toml`].
+    ///
+    /// # Errors
+    /// Returns an `InvalidData` error if the text cannot be parsed.
+    #[cfg(feature = "serde")]
+    pub fn from_json(text: &str) -> io::Result<Self> {
+        Ok(serde_json::from_str(text)?)
+    }
+
+    /// Install this theme for every thread; configurations created afterwards
+    /// take their default styles from it.
+    pub fn install(self) {
+        *INSTALLED
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = self;
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+/// The installed theme; [`Theme::dark`] unless another was installed.
+pub fn current() -> Theme {
+    *INSTALLED
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// The user's theme file for the application `app`: `theme.toml` or
+/// `theme.json` in `$XDG_CONFIG_HOME/<app>`, or in `~/.config/<app>` when
+/// `XDG_CONFIG_HOME` is not set. `None` if neither file exists.
+#[cfg(feature = "serde")]
+pub fn user_theme_path(app: &str) -> Option<PathBuf> {
+    let config_dir = env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+    ["theme.toml", "theme.json"]
+        .into_iter()
+        .map(|name| config_dir.join(app).join(name))
+        .find(|path| path.is_file())
+}
+
+/// Load and install the user's theme file for the application `app` (see
+/// [`user_theme_path`]), so end users can restyle its prompts, lists and
+/// output. Returns `false` and keeps the installed theme if there is no file.
+///
+/// Call it at startup, before creating configurations:
+///
+/// ```rust,no_run
+/// use prettui::theme;
+///
+/// // Reads ~/.config/mytool/theme.toml if it exists
+/// if let Err(err) = theme::load_user_theme("mytool") {
+///     eprintln!("Ignoring theme file: {}", err);
+/// }
+/// ```
+///
+/// # Errors
+/// Returns an `io::Error` if the file cannot be read or parsed; the
+/// installed theme is left unchanged.
+#[cfg(feature = "serde")]
+pub fn load_user_theme(app: &str) -> io::Result<bool> {
+    let Some(path) = user_theme_path(app) else {
+        return Ok(false);
+    };
+    Theme::from_file(&path)
+        .map_err(|err| io::Error::new(err.kind(), format!("{}: {}", path.display(), err)))?
+        .install();
+    Ok(true)
+}
@@ -0,0 +1,163 @@
+//! Preset answers for prompts and lists, run headless.
+
+use prettui::backend::{with_backend, VirtualTerminal};
+use prettui::io::answers::{self, Answers};
+use prettui::io::input::{read_input, InputConfig};
+use prettui::io::prompt::{read_number, NumberConfig};
+use prettui::list::{choose_from_list, choose_many_from_list, ListConfig, ListEntry};
+use std::io;
+use std::sync::Mutex;
+
+/// Installed answers are global, so tests using them run one at a time.
+static ANSWERS: Mutex<()> = Mutex::new(());
+
+fn items() -> Vec<ListEntry> {
+    vec![
+        ListEntry::separator("Project"),
+        ListEntry::new("Cargo.toml"),
+        ListEntry::new("README.md"),
+        ListEntry {
+            disabled: true,
+            ..ListEntry::new("Cargo.lock")
+        },
+        ListEntry::separator(""),
+        ListEntry::new("LICENSE"),
+    ]
+}
+
+/// Run `f` on a piped virtual terminal with `answers` installed.
+fn with_answers<R>(answers: Answers, f: impl FnOnce() -> R) -> R {
+    let _guard = ANSWERS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    answers.install();
+    let vt = VirtualTerminal::new(80, 24);
+    vt.set_terminal(false);
+    let result = with_backend(vt, f);
+    answers::clear();
+    result
+}
+
+/// Answer the list keyed `file` with `answer`.
+fn answer_one(answer: &str, config: &ListConfig) -> Result<Option<usize>, String> {
+    let config = config.clone().answer_key("file");
+    with_answers(Answers::new().answer("file", answer), || {
+        choose_from_list(&items(), &config)
+    })
+    .map_err(|err| err.to_string())
+}
+
+/// Answer the multi-select list keyed `files` with `answer`.
+fn answer_many(answer: &str, config: &ListConfig) -> Result<Option<Vec<usize>>, String> {
+    let config = config.clone().answer_key("files");
+    with_answers(Answers::new().answer("files", answer), || {
+        choose_many_from_list(&items(), &config)
+    })
+    .map_err(|err| err.to_string())
+}
+
+#[test]
+fn label_answers_skip_separators() {
+    let config = ListConfig::default();
+    assert_eq!(answer_one("README.md", &config), Ok(Some(2)));
+    assert_eq!(answer_one("license", &config), Ok(Some(5)));
+    assert_eq!(
+        answer_one("Project", &config),
+        Err(String::from(
+            "Invalid answer for 'file': 'Project' is not in the list"
+        ))
+    );
+}
+
+#[test]
+fn number_answers_count_items_only() {
+    let config = ListConfig::default();
+    assert_eq!(answer_one("1", &config), Ok(Some(1)));
+    assert_eq!(answer_one("4", &config), Ok(Some(5)));
+    assert_eq!(
+        answer_one("5", &config),
+        Err(String::from(
+            "Invalid answer for 'file': '5' is not in the list"
+        ))
+    );
+}
+
+#[test]
+fn disabled_items_cannot_be_answered() {
+    assert_eq!(
+        answer_one("Cargo.lock", &ListConfig::default()),
+        Err(String::from(
+            "Invalid answer for 'file': 'Cargo.lock' cannot be chosen"
+        ))
+    );
+    assert_eq!(
+        answer_many("1, 3", &ListConfig::default()),
+        Err(String::from(
+            "Invalid answer for 'files': '3' cannot be chosen"
+        ))
+    );
+}
+
+#[test]
+fn comma_answers_mix_numbers_and_labels() {
+    let config = ListConfig::default();
+    assert_eq!(
+        answer_many("4, readme.md,1", &config),
+        Ok(Some(vec![1, 2, 5]))
+    );
+    assert_eq!(answer_many("1,Cargo.toml", &config), Ok(Some(vec![1])));
+}
+
+#[test]
+fn comma_answers_respect_limits() {
+    let config = ListConfig::default().min_selected(2).max_selected(2);
+    assert_eq!(answer_many("1,2", &config), Ok(Some(vec![1, 2])));
+    assert_eq!(
+        answer_many("1", &config),
+        Err(String::from(
+            "Invalid answer for 'files': Select at least 2"
+        ))
+    );
+    assert_eq!(
+        answer_many("1,2,4", &config),
+        Err(String::from("Invalid answer for 'files': Select at most 2"))
+    );
+}
+
+#[test]
+fn input_answers_use_the_answer_key() {
+    let cfg = InputConfig {
+        answer_key: Some(String::from("user name")),
+        ..Default::default()
+    };
+    let answers = Answers::new().answer("user_name", "alice");
+    assert_eq!(with_answers(answers, || read_input(&cfg)).unwrap(), "alice");
+}
+
+#[test]
+fn defaults_answer_prompts_without_an_answer() {
+    let cfg = NumberConfig {
+        default: Some(3),
+        ..Default::default()
+    };
+    let answers = Answers::new().assume_defaults(true);
+    let retries = with_answers(answers, || {
+        read_number("Retries", &cfg, &InputConfig::default())
+    });
+    assert_eq!(retries.unwrap(), 3);
+}
+
+#[test]
+fn missing_answers_fail_without_a_terminal() {
+    let answers = Answers::new().env_prefix("PRETTUI_TEST");
+    let err = with_answers(answers, || {
+        read_number("Workers", &NumberConfig::default(), &InputConfig::default())
+    })
+    .unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    assert_eq!(
+        err.to_string(),
+        "No answer for 'workers' and input is not a terminal \
+         (set PRETTUI_TEST_WORKERS or add it to the answers)"
+    );
+}
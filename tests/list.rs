@@ -0,0 +1,18 @@
+//! Interactive lists driven through a virtual terminal.
+
+use prettui::backend::{with_backend, VirtualTerminal};
+use prettui::list::{choose_many_from_list, KeyCode, ListConfig};
+
+#[test]
+fn confirm_rejects_preselection_above_max() {
+    let vt = VirtualTerminal::new(80, 24);
+    vt.push_key(KeyCode::Enter);
+    vt.push_key(KeyCode::Char(' '));
+    vt.push_key(KeyCode::Enter);
+    let config = ListConfig::default().preselected([0, 1, 2]).max_selected(2);
+    let chosen = with_backend(vt.clone(), || {
+        choose_many_from_list(&["alpha", "beta", "gamma"], &config)
+    });
+    assert_eq!(chosen.unwrap(), Some(vec![1, 2]));
+    assert!(vt.output().contains("Select at most 2"));
+}
@@ -0,0 +1,133 @@
+//! Plain fallbacks of lists, input and output when not on a terminal, run
+//! headless.
+
+use prettui::backend::{with_backend, VirtualTerminal};
+use prettui::io::input::{read_input, InputConfig};
+use prettui::io::output::{write_output, OutputConfig};
+use prettui::list::{choose_from_list, choose_many_from_list, ListConfig, ListEntry};
+
+fn items() -> Vec<ListEntry> {
+    vec![
+        ListEntry::separator("Project"),
+        ListEntry::new("Cargo.toml"),
+        ListEntry::new("README.md"),
+        ListEntry {
+            disabled: true,
+            ..ListEntry::new("Cargo.lock")
+        },
+        ListEntry::separator(""),
+        ListEntry::new("LICENSE"),
+    ]
+}
+
+/// A virtual terminal behaving as if piped, with `lines` as its input.
+fn piped(lines: &[&str]) -> VirtualTerminal {
+    let vt = VirtualTerminal::new(80, 40);
+    vt.set_terminal(false);
+    for line in lines {
+        vt.type_line(line);
+    }
+    vt
+}
+
+/// Whether every escape sequence in `output` only sets colors (SGR).
+fn only_colors(output: &str) -> bool {
+    output.split('\x1b').skip(1).all(|sequence| {
+        sequence
+            .strip_prefix('[')
+            .and_then(|rest| rest.find(|c: char| !c.is_ascii_digit() && c != ';'))
+            .is_some_and(|end| sequence[1 + end..].starts_with('m'))
+    })
+}
+
+#[test]
+fn plain_list_reads_labels_and_numbers() {
+    let vt = piped(&["readme.md"]);
+    let chosen = with_backend(vt.clone(), || {
+        choose_from_list(&items(), &ListConfig::default())
+    });
+    assert_eq!(chosen.unwrap(), Some(2));
+
+    let screen = vt.screen_text();
+    assert!(screen.contains("  1) Cargo.toml"));
+    assert!(screen.contains("  3) Cargo.lock (disabled)"));
+    assert!(screen.contains("  4) LICENSE"));
+    assert!(screen.contains("Enter a number [1]:"));
+    assert!(!vt.is_raw_mode());
+    assert!(only_colors(&vt.output()));
+}
+
+#[test]
+fn plain_list_retries_until_the_choice_is_valid() {
+    let vt = piped(&["Project", "9", "3", "1,2", "4"]);
+    let chosen = with_backend(vt.clone(), || {
+        choose_from_list(&items(), &ListConfig::default())
+    });
+    assert_eq!(chosen.unwrap(), Some(5));
+
+    let screen = vt.screen_text();
+    assert!(screen.contains("Error: 'Project' is not in the list"));
+    assert!(screen.contains("Error: '9' is not in the list"));
+    assert!(screen.contains("Error: '3' cannot be chosen"));
+    assert!(screen.contains("Error: '1,2' is not in the list"));
+}
+
+#[test]
+fn plain_list_accepts_the_default_and_stops_at_end_of_input() {
+    let vt = piped(&[""]);
+    let chosen = with_backend(vt, || choose_from_list(&items(), &ListConfig::default()));
+    assert_eq!(chosen.unwrap(), Some(1));
+
+    let chosen = with_backend(piped(&[]), || {
+        choose_from_list(&items(), &ListConfig::default())
+    });
+    assert_eq!(chosen.unwrap(), None);
+}
+
+#[test]
+fn plain_multi_list_enforces_selection_limits() {
+    let vt = piped(&["1", "1, 2, LICENSE", "2,4"]);
+    let config = ListConfig::default().min_selected(2).max_selected(2);
+    let chosen = with_backend(vt.clone(), || choose_many_from_list(&items(), &config));
+    assert_eq!(chosen.unwrap(), Some(vec![2, 5]));
+
+    let screen = vt.screen_text();
+    assert!(screen.contains("Error: Select at least 2"));
+    assert!(screen.contains("Error: Select at most 2"));
+}
+
+#[test]
+fn plain_multi_list_defaults_to_preselected_items() {
+    let vt = piped(&[""]);
+    let config = ListConfig::default().preselected([2, 5]);
+    let chosen = with_backend(vt.clone(), || choose_many_from_list(&items(), &config));
+    assert_eq!(chosen.unwrap(), Some(vec![2, 5]));
+
+    let screen = vt.screen_text();
+    assert!(screen.contains("  1) [ ] Cargo.toml"));
+    assert!(screen.contains("  2) [x] README.md"));
+    assert!(screen.contains("Enter numbers separated by commas [2,4]:"));
+}
+
+#[test]
+fn plain_input_reads_lines() {
+    let vt = piped(&["typed", ""]);
+    let cfg = InputConfig {
+        default: Some(String::from("fallback")),
+        ..Default::default()
+    };
+    let lines = with_backend(vt.clone(), || (read_input(&cfg), read_input(&cfg)));
+    assert_eq!(lines.0.unwrap(), "typed");
+    assert_eq!(lines.1.unwrap(), "fallback");
+    assert!(!vt.is_raw_mode());
+    assert!(only_colors(&vt.output()));
+}
+
+#[test]
+fn plain_output_keeps_only_colors() {
+    let vt = piped(&[]);
+    let cfg = OutputConfig::default();
+    with_backend(vt.clone(), || write_output(&cfg, "Build finished")).unwrap();
+    assert!(vt.screen_text().contains("Build finished"));
+    assert!(only_colors(&vt.output()));
+}